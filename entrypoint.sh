@@ -1,3 +1,3 @@
 #!/bin/sh
 
-trunk serve --release --address 0.0.0.0 --port 8080
+DIST_DIR=./dist ADDRESS=0.0.0.0:8080 ./target/release/static_server
@@ -0,0 +1,160 @@
+## General
+
+language = Sprache
+theme = Farbschema
+theme-system = System
+theme-light = Hell
+theme-dark = Dunkel
+theme-high-contrast = Hoher Kontrast
+loading = Wird geladen...
+submit = Absenden
+cancel = Abbrechen
+save = Speichern
+log-out = Abmelden
+current-pass-word = Aktuelles Passwort
+
+## Layout
+
+app-name = Yew App
+menu = Menü
+nav-home = Startseite
+nav-not-found = Seite nicht gefunden
+meta-home-description = Ein Yew-Webclient mit Konten, Sitzungen und Datenexport.
+meta-login-description = Melde dich an, registriere ein neues Konto oder setze dein Passwort zurück.
+meta-legal-description = Rechtliche Informationen zu dieser Seite und zum Umgang mit deinen Daten.
+
+## Session notices
+
+notice-session-expired = Deine Sitzung ist abgelaufen. Bitte melde dich erneut an.
+notice-idle-logout = Du wurdest wegen Inaktivität abgemeldet.
+notice-logged-out-everywhere = Du wurdest auf allen Geräten abgemeldet.
+
+## Login screen
+
+login-title = Anmeldung
+login-user-info = Benutzername oder E-Mail
+login-pass-word = Passwort
+login-remember-me = Angemeldet bleiben
+login-requires-consent = (erfordert Einwilligung)
+login-forgot-password = Passwort vergessen?
+login-register = Neues Konto registrieren
+login-throttled = Zu viele Versuche. Bitte versuche es in { $secs } Sekunden erneut.
+login-back-to-login = Zurück zur Anmeldung
+registration-title = Registrierung
+registration-user-name = Benutzername
+registration-mail-addr = E-Mail
+registration-pwconfirm = Passwort bestätigen
+registration-accept-terms = Ich akzeptiere die
+password-reset-title = Passwort zurücksetzen
+
+## Account screen
+
+account-title = Konto
+account-change-pass-word = Passwort ändern
+account-new-pass-word = Neues Passwort
+account-new-pwconfirm = Neues Passwort bestätigen
+account-change-mail-addr = E-Mail ändern
+account-new-mail-addr = Neue E-Mail
+account-verify-mail-addr =
+    Wir haben einen Bestätigungslink an { $mail_addr } gesendet. Sieh in deinem Postfach nach,
+    deine E-Mail-Adresse ändert sich, sobald du den Link öffnest.
+account-change-user-name = Benutzernamen ändern
+account-new-user-name = Neuer Benutzername
+account-logged-in-as = Angemeldet als { $user_info }.
+account-update-failed = Die Änderungen konnten nicht gespeichert werden ({ $status }).
+
+## Devices and sessions
+
+sessions-title = Geräte & Sitzungen
+sessions-device = Gerät
+sessions-browser = Browser
+sessions-ip-addr = IP-Adresse
+sessions-last-seen = Zuletzt aktiv
+sessions-this-device = Dieses Gerät
+sessions-revoke = Widerrufen
+sessions-log-out-everywhere = Überall abmelden
+sessions-load-failed = Sitzungen konnten nicht geladen werden ({ $status }).
+
+## Data export
+
+export-title = Meine Daten exportieren
+export-description = Lade eine Kopie aller personenbezogenen Daten herunter, die zu deinem Konto gespeichert sind.
+export-progress = Deine Daten werden vorbereitet... ({ $percent } %)
+export-done = Deine Daten wurden heruntergeladen.
+export-request-failed = Export konnte nicht angefordert werden ({ $status }).
+export-query-failed = Exportstatus konnte nicht abgefragt werden ({ $status }).
+export-download-failed = Export konnte nicht heruntergeladen werden ({ $status }).
+
+## Account deletion
+
+deletion-title = Konto löschen
+deletion-expand = Konto löschen...
+deletion-description =
+    Beim Löschen deines Kontos werden dein Profil und alle zugehörigen Daten entfernt.
+    Alle deine Sitzungen werden beendet, sobald die Löschung durchgeführt wird.
+    Bis dahin kann die Löschung rückgängig gemacht werden.
+deletion-confirm-user-name = Gib zur Bestätigung deinen Benutzernamen ein
+deletion-user-name-mismatch = Der Benutzername passt nicht zu deinem Konto.
+deletion-failed = Das Konto konnte nicht gelöscht werden ({ $status }).
+deletion-scheduled = Dein Konto wird am { $date } gelöscht.
+deletion-undo = Rückgängig
+
+## Idle timeout
+
+idle-title = Bist du noch da?
+idle-countdown = Du wirst in { $secs } Sekunden wegen Inaktivität abgemeldet.
+idle-stay-logged-in = Angemeldet bleiben
+
+## Consent
+
+consent-description =
+    Diese Seite speichert Daten in deinem Browser. Notwendiger Speicher wird für die
+    Anmeldung benötigt, alles andere wird nur mit deiner Einwilligung verwendet.
+consent-settings = Einstellungen
+consent-essential-only = Nur notwendige
+consent-accept-all = Alle akzeptieren
+consent-settings-title = Speichereinstellungen
+consent-essential = Notwendig: erforderlich für die Anmeldung und zum Speichern dieser Einstellungen.
+consent-remember-me = Angemeldet bleiben: hält dich auch nach einem Neustart des Browsers angemeldet.
+consent-preferences = Präferenzen: merkt sich Einstellungen wie Sprache und Farbschema.
+consent-analytics = Statistik: hilft uns zu verstehen, wie die Seite genutzt wird.
+
+## Legal documents
+
+legal-imprint = Impressum
+legal-privacy-policy = Datenschutzerklärung
+legal-terms-of-service = Nutzungsbedingungen
+legal-version = Version { $version }
+terms-updated-title = Aktualisierte Nutzungsbedingungen
+terms-updated-description = Unsere Nutzungsbedingungen haben sich geändert. Bitte lies und akzeptiere sie, um fortzufahren.
+terms-accept-version = Ich akzeptiere die Nutzungsbedingungen (Version { $version }).
+terms-accept = Akzeptieren
+terms-not-accepted = Bitte akzeptiere die Nutzungsbedingungen.
+
+## Page not found
+
+not-found-path = Unter { $path } gibt es keine Seite.
+not-found-suggestion = Meintest du:
+not-found-home = Zur Startseite
+not-found-back = Zurück
+
+## Server messages
+
+login-successful = Erfolgreich angemeldet.
+registration-successful = Dein Konto wurde erstellt.
+password-reset-successful = Eine E-Mail zum Zurücksetzen deines Passworts wurde verschickt.
+login-error-invalid-login-credentials = Ungültiger Benutzername, E-Mail oder Passwort.
+login-error-empty-user-info = Bitte gib deinen Benutzernamen oder deine E-Mail ein.
+login-error-empty-pass-word = Bitte gib dein Passwort ein.
+registration-error-empty-user-name = Bitte gib einen Benutzernamen ein.
+registration-error-user-name-exists-already = Dieser Benutzername ist bereits vergeben.
+registration-error-invalid-user-name-format = Dieser Benutzername enthält ungültige Zeichen.
+registration-error-empty-mail-address = Bitte gib eine E-Mail-Adresse ein.
+registration-error-mail-address-exists-already = Diese E-Mail-Adresse ist bereits registriert.
+registration-error-invalid-mail-address-format = Diese E-Mail-Adresse ist ungültig.
+registration-error-empty-pass-word = Bitte gib ein Passwort ein.
+registration-error-invalid-password-format = Dieses Passwort erfüllt nicht die Anforderungen.
+registration-error-empty-pass-word-confirm = Bitte bestätige dein Passwort.
+registration-error-invalid-password-confirmation = Die Passwörter stimmen nicht überein.
+password-reset-error-empty-user-info = Bitte gib deinen Benutzernamen oder deine E-Mail ein.
+password-reset-error-account-does-not-exist = Es gibt kein Konto mit diesem Benutzernamen oder dieser E-Mail.
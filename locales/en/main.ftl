@@ -0,0 +1,160 @@
+## General
+
+language = Language
+theme = Theme
+theme-system = System
+theme-light = Light
+theme-dark = Dark
+theme-high-contrast = High contrast
+loading = Loading...
+submit = Submit
+cancel = Cancel
+save = Save
+log-out = Log out
+current-pass-word = Current Password
+
+## Layout
+
+app-name = Yew App
+menu = Menu
+nav-home = Home
+nav-not-found = Page not found
+meta-home-description = A Yew web client with accounts, sessions and data export.
+meta-login-description = Log in, register a new account or reset your password.
+meta-legal-description = Legal information about this site and how it handles your data.
+
+## Session notices
+
+notice-session-expired = Your session has expired. Please log in again.
+notice-idle-logout = You have been logged out due to inactivity.
+notice-logged-out-everywhere = You have been logged out on all devices.
+
+## Login screen
+
+login-title = Login
+login-user-info = Username or E-Mail
+login-pass-word = Password
+login-remember-me = Remember me
+login-requires-consent = (requires consent)
+login-forgot-password = Forgot Password?
+login-register = Register new Account
+login-throttled = Too many attempts. Please try again in { $secs } seconds.
+login-back-to-login = Back to Login
+registration-title = Registration
+registration-user-name = Username
+registration-mail-addr = E-Mail
+registration-pwconfirm = Confirm Password
+registration-accept-terms = I accept the
+password-reset-title = Password Reset
+
+## Account screen
+
+account-title = Account
+account-change-pass-word = Change Password
+account-new-pass-word = New Password
+account-new-pwconfirm = Confirm New Password
+account-change-mail-addr = Change E-Mail
+account-new-mail-addr = New E-Mail
+account-verify-mail-addr =
+    We sent a confirmation link to { $mail_addr }. Check your inbox,
+    your e-mail address changes once you open the link.
+account-change-user-name = Change Username
+account-new-user-name = New Username
+account-logged-in-as = Logged in as { $user_info }.
+account-update-failed = Failed to save the changes ({ $status }).
+
+## Devices and sessions
+
+sessions-title = Devices & Sessions
+sessions-device = Device
+sessions-browser = Browser
+sessions-ip-addr = IP Address
+sessions-last-seen = Last Seen
+sessions-this-device = This device
+sessions-revoke = Revoke
+sessions-log-out-everywhere = Log out everywhere
+sessions-load-failed = Failed to load sessions ({ $status }).
+
+## Data export
+
+export-title = Export my Data
+export-description = Download a copy of all personal data stored about your account.
+export-progress = Preparing your data... ({ $percent }%)
+export-done = Your data has been downloaded.
+export-request-failed = Failed to request export ({ $status }).
+export-query-failed = Failed to query export ({ $status }).
+export-download-failed = Failed to download export ({ $status }).
+
+## Account deletion
+
+deletion-title = Delete Account
+deletion-expand = Delete Account...
+deletion-description =
+    Deleting your account removes your profile and all associated data.
+    All of your sessions will be ended once the deletion is carried out.
+    Until then, the deletion can be undone.
+deletion-confirm-user-name = Type your username to confirm
+deletion-user-name-mismatch = The username does not match your account.
+deletion-failed = Failed to delete account ({ $status }).
+deletion-scheduled = Your account is scheduled for deletion on { $date }.
+deletion-undo = Undo
+
+## Idle timeout
+
+idle-title = Are you still there?
+idle-countdown = You will be logged out in { $secs } seconds due to inactivity.
+idle-stay-logged-in = Stay logged in
+
+## Consent
+
+consent-description =
+    This site stores data in your browser. Essential storage is required for
+    logging in, everything else is only used with your consent.
+consent-settings = Settings
+consent-essential-only = Essential only
+consent-accept-all = Accept all
+consent-settings-title = Storage Settings
+consent-essential = Essential: required for logging in and for storing these settings.
+consent-remember-me = Remember me: keeps you logged in across browser restarts.
+consent-preferences = Preferences: remembers settings such as your language and theme.
+consent-analytics = Analytics: helps us understand how the site is used.
+
+## Legal documents
+
+legal-imprint = Imprint
+legal-privacy-policy = Privacy Policy
+legal-terms-of-service = Terms of Service
+legal-version = Version { $version }
+terms-updated-title = Updated Terms of Service
+terms-updated-description = Our terms of service have changed. Please review and accept them to continue.
+terms-accept-version = I accept the terms of service (version { $version }).
+terms-accept = Accept
+terms-not-accepted = Please accept the terms of service.
+
+## Page not found
+
+not-found-path = There is no page at { $path }.
+not-found-suggestion = Did you mean:
+not-found-home = Go to the home page
+not-found-back = Go back
+
+## Server messages
+
+login-successful = Logged in successfully.
+registration-successful = Your account has been created.
+password-reset-successful = An e-mail to reset your password has been sent.
+login-error-invalid-login-credentials = Invalid username, e-mail or password.
+login-error-empty-user-info = Please enter your username or e-mail.
+login-error-empty-pass-word = Please enter your password.
+registration-error-empty-user-name = Please enter a username.
+registration-error-user-name-exists-already = This username is already taken.
+registration-error-invalid-user-name-format = This username contains invalid characters.
+registration-error-empty-mail-address = Please enter an e-mail address.
+registration-error-mail-address-exists-already = This e-mail address is already registered.
+registration-error-invalid-mail-address-format = This e-mail address is invalid.
+registration-error-empty-pass-word = Please enter a password.
+registration-error-invalid-password-format = This password does not meet the requirements.
+registration-error-empty-pass-word-confirm = Please confirm your password.
+registration-error-invalid-password-confirmation = The passwords do not match.
+password-reset-error-empty-user-info = Please enter your username or e-mail.
+password-reset-error-account-does-not-exist = There is no account with this username or e-mail.
@@ -0,0 +1,115 @@
+use crate::components::{
+    account_deletion::PendingDeletionBanner, consent_banner::ConsentBanner,
+    idle_timeout::IdleTimeoutDialog, layout::Layout, terms_gate::TermsGate,
+};
+use crate::services::{
+    consent::ConsentProvider,
+    i18n::{I18nProvider, Locale},
+    routing::{self, LocalizedSwitch, RouterMode},
+    session::SessionProvider,
+    theme::{self, ThemeProvider},
+};
+#[cfg(feature = "ssr")]
+use crate::services::{head::HeadCollector, i18n};
+#[cfg(feature = "ssr")]
+use std::collections::HashMap;
+use stylist::yew::{styled_component, Global};
+#[cfg(feature = "ssr")]
+use stylist::{manager::StyleManager, yew::ManagerProvider};
+use yew::prelude::*;
+#[cfg(feature = "ssr")]
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct AppContentProps {
+    #[prop_or_default]
+    locale: Option<Locale>,
+}
+
+// Everything below the router, shared by the browser and the server.
+#[styled_component]
+fn AppContent(props: &AppContentProps) -> Html {
+    let token_style = use_memo((), |_| theme::token_style());
+    let class = css!(
+        "
+            min-height: 100vh;
+            font-family: var(--font-family);
+            background-color: var(--color-background);
+            color: var(--color-text);
+
+            a {
+                color: var(--color-link);
+            }
+        "
+    );
+
+    html! {
+        <div {class}>
+            <Global css={ (*token_style).clone() } />
+            <ConsentProvider>
+                <ThemeProvider>
+                    <I18nProvider locale={ props.locale }>
+                        <SessionProvider>
+                            <Layout>
+                                <PendingDeletionBanner />
+                                <TermsGate>
+                                    <LocalizedSwitch />
+                                </TermsGate>
+                            </Layout>
+                            <IdleTimeoutDialog />
+                            <ConsentBanner />
+                        </SessionProvider>
+                    </I18nProvider>
+                </ThemeProvider>
+            </ConsentProvider>
+        </div>
+    }
+}
+
+#[function_component]
+pub fn App() -> Html {
+    let basename = Some(routing::base_path()).filter(|path| !path.is_empty());
+    match routing::router_mode() {
+        RouterMode::Browser => html! {
+            <BrowserRouter basename={ basename.map(AttrValue::from) }>
+                <AppContent />
+            </BrowserRouter>
+        },
+        RouterMode::Hash => html! {
+            <HashRouter>
+                <AppContent />
+            </HashRouter>
+        },
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Properties, PartialEq)]
+pub struct ServerAppProps {
+    pub url: AttrValue,
+    pub queries: HashMap<String, String>,
+    pub manager: StyleManager,
+    pub head: HeadCollector,
+}
+
+// Renders the app for a single URL, collecting its styles and head on the way.
+#[cfg(feature = "ssr")]
+#[function_component]
+pub fn ServerApp(props: &ServerAppProps) -> Html {
+    let history = AnyHistory::from(MemoryHistory::new());
+    if let Err(err) = history.push_with_query(&*props.url, &props.queries) {
+        eprintln!("failed to push `{}`: {}", props.url, err);
+    }
+    let locale = i18n::path_locale(&props.url);
+
+    html! {
+        <ManagerProvider manager={ props.manager.clone() }>
+            <ContextProvider<HeadCollector> context={ props.head.clone() }>
+                <Router { history }>
+                    <AppContent { locale } />
+                </Router>
+            </ContextProvider<HeadCollector>>
+        </ManagerProvider>
+    }
+}
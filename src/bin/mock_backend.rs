@@ -0,0 +1,734 @@
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    http::{
+        header::{AUTHORIZATION, CONTENT_TYPE, COOKIE, RETRY_AFTER, SET_COOKIE, USER_AGENT},
+        HeaderMap, HeaderValue, Method, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    routing::{delete, get, post, put},
+    Json, Router,
+};
+use rs_web_api_models::{
+    api_message::{ApiError, ApiMessage, ApiOk, LoginError, PasswordResetError, RegistrationError},
+    validation,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    env,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tower_http::cors::CorsLayer;
+
+const REFRESH_COOKIE: &str = "refresh_token";
+// Remembered sessions keep their cookie across browser restarts, all others lose it.
+const REMEMBERED_COOKIE_MAX_AGE_SECS: u32 = 30 * 24 * 60 * 60;
+const DELETION_GRACE_PERIOD: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+// Exports report their progress while this time passes, so that the polling can be seen.
+const EXPORT_DURATION: Duration = Duration::from_secs(6);
+
+#[derive(Clone)]
+struct User {
+    user_name: String,
+    mail_addr: String,
+    pass_hash: String,
+    accepted_terms: Option<String>,
+    deletion_scheduled_for: Option<SystemTime>,
+}
+
+struct MockSession {
+    id: String,
+    user_name: String,
+    user_agent: String,
+    ip_addr: String,
+    last_seen: SystemTime,
+}
+
+struct ExportJob {
+    user_name: String,
+    started: Instant,
+}
+
+// Injected in place of the regular response of an endpoint, until it is removed again.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Failure {
+    Error(ApiError),
+    Throttled { retry_after: u32 },
+    Status(u16),
+}
+
+#[derive(Default)]
+struct MockState {
+    users: Vec<User>,
+    // Sessions by their refresh token, and the refresh token each access token belongs to.
+    sessions: HashMap<String, MockSession>,
+    access_tokens: HashMap<String, String>,
+    exports: HashMap<String, ExportJob>,
+    next_token: u64,
+    latency: Duration,
+    failures: HashMap<String, Failure>,
+}
+
+type SharedState = Arc<Mutex<MockState>>;
+
+impl MockState {
+    fn find_user(&self, user_info: &str) -> Option<&User> {
+        self.users
+            .iter()
+            .find(|user| user.user_name == user_info || user.mail_addr == user_info)
+    }
+
+    fn find_user_mut(&mut self, user_name: &str) -> Option<&mut User> {
+        self.users
+            .iter_mut()
+            .find(|user| user.user_name == user_name)
+    }
+
+    fn new_token(&mut self) -> String {
+        self.next_token += 1;
+        format!("mock-token-{}", self.next_token)
+    }
+
+    // The refresh token and user of the session the request's access token belongs to.
+    fn authorize(&mut self, headers: &HeaderMap) -> Option<(String, String)> {
+        let refresh_token = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|access_token| self.access_tokens.get(access_token))
+            .cloned();
+        let session = refresh_token
+            .as_ref()
+            .and_then(|refresh_token| self.sessions.get_mut(refresh_token));
+        let (Some(refresh_token), Some(session)) = (refresh_token.clone(), session) else {
+            return None;
+        };
+        session.last_seen = SystemTime::now();
+        Some((refresh_token, session.user_name.clone()))
+    }
+}
+
+// RFC 3339 in UTC, which the client parses with `Date`. The days since the epoch are
+// converted to the calendar with Howard Hinnant's `civil_from_days` algorithm.
+fn timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs) = ((secs / 86_400) as i64, secs % 86_400);
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    )
+}
+
+// A rough guess from the user agent, which is all the session list needs.
+fn describe_user_agent(user_agent: &str) -> (&'static str, &'static str) {
+    let device = match user_agent.contains("Mobile") {
+        true => "Mobile",
+        false => "Desktop",
+    };
+    let browser = [
+        ("Firefox", "Firefox"),
+        ("Edg", "Edge"),
+        ("Chrome", "Chrome"),
+        ("Safari", "Safari"),
+    ]
+    .into_iter()
+    .find(|(token, _)| user_agent.contains(token))
+    .map_or("Unknown", |(_, browser)| browser);
+    (device, browser)
+}
+
+// The success messages mirror the variants of the models crate for each endpoint.
+fn success(endpoint: &str) -> ApiMessage {
+    match endpoint {
+        "login" => ApiMessage::Ok(ApiOk::LoginSuccessful),
+        "register" => ApiMessage::Ok(ApiOk::RegistrationSuccessful),
+        _ => ApiMessage::Ok(ApiOk::PasswordResetSuccessful),
+    }
+}
+
+fn error(err: ApiError) -> Response {
+    Json(ApiMessage::Err(err)).into_response()
+}
+
+// The account endpoints answer errors with an error status, as their successful responses
+// are no `ApiMessage`.
+fn account_error(status: StatusCode, err: ApiError) -> Response {
+    (status, Json(ApiMessage::Err(err))).into_response()
+}
+
+// Waits for the configured latency and returns the injected failure of the endpoint, if any.
+async fn simulate(state: &SharedState, endpoint: &str) -> Option<Response> {
+    let (latency, failure) = {
+        let state = state.lock().unwrap();
+        (state.latency, state.failures.get(endpoint).cloned())
+    };
+    tokio::time::sleep(latency).await;
+    let response = match failure? {
+        Failure::Error(err) => error(err),
+        Failure::Throttled { retry_after } => {
+            let retry_after = HeaderValue::from(retry_after);
+            (StatusCode::TOO_MANY_REQUESTS, [(RETRY_AFTER, retry_after)]).into_response()
+        }
+        Failure::Status(status) => StatusCode::from_u16(status)
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+            .into_response(),
+    };
+    Some(response)
+}
+
+fn refresh_token(headers: &HeaderMap) -> Option<String> {
+    let cookies = headers.get(COOKIE)?.to_str().ok()?;
+    cookies
+        .split(';')
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == REFRESH_COOKIE)
+        .map(|(_, value)| value.to_string())
+}
+
+#[derive(Deserialize)]
+struct LoginRequest {
+    user_info: String,
+    pass_hash: String,
+    #[serde(default)]
+    remember_me: bool,
+}
+
+async fn login(
+    State(state): State<SharedState>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Json(request): Json<LoginRequest>,
+) -> Response {
+    if let Some(response) = simulate(&state, "login").await {
+        return response;
+    }
+    if request.user_info.is_empty() {
+        return error(ApiError::LoginError(LoginError::EmptyUserInfo));
+    }
+    if request.pass_hash.is_empty() {
+        return error(ApiError::LoginError(LoginError::EmptyPassWord));
+    }
+
+    let mut state = state.lock().unwrap();
+    let user = state
+        .find_user(&request.user_info)
+        .filter(|user| user.pass_hash == request.pass_hash)
+        .cloned();
+    let Some(user) = user else {
+        return error(ApiError::LoginError(LoginError::InvalidLoginCredentials));
+    };
+    let token = state.new_token();
+    let session = MockSession {
+        id: state.new_token(),
+        user_name: user.user_name,
+        user_agent: headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string(),
+        ip_addr: address.ip().to_string(),
+        last_seen: SystemTime::now(),
+    };
+    state.sessions.insert(token.clone(), session);
+    let mut cookie = format!(
+        "{}={}; HttpOnly; Path=/api; SameSite=Lax",
+        REFRESH_COOKIE, token
+    );
+    if request.remember_me {
+        cookie.push_str(&format!("; Max-Age={}", REMEMBERED_COOKIE_MAX_AGE_SECS));
+    }
+    ([(SET_COOKIE, cookie)], Json(success("login"))).into_response()
+}
+
+#[derive(Deserialize)]
+struct RegistrationRequest {
+    user_name: String,
+    mail_addr: String,
+    pass_hash: String,
+    #[serde(default)]
+    terms_version: Option<String>,
+}
+
+fn registration_error(
+    state: &MockState,
+    request: &RegistrationRequest,
+) -> Option<RegistrationError> {
+    let users = &state.users;
+    let err = if request.user_name.is_empty() {
+        RegistrationError::EmptyUserName
+    } else if !validation::is_valid_user_name(&request.user_name) {
+        RegistrationError::InvalidUserNameFormat
+    } else if users.iter().any(|user| user.user_name == request.user_name) {
+        RegistrationError::UserNameExistsAlready
+    } else if request.mail_addr.is_empty() {
+        RegistrationError::EmptyMailAddress
+    } else if !validation::is_valid_mail_addr(&request.mail_addr) {
+        RegistrationError::InvalidMailAddressFormat
+    } else if users.iter().any(|user| user.mail_addr == request.mail_addr) {
+        RegistrationError::MailAddressExistsAlready
+    } else if request.pass_hash.is_empty() {
+        RegistrationError::EmptyPassWord
+    } else {
+        return None;
+    };
+    Some(err)
+}
+
+async fn register(
+    State(state): State<SharedState>,
+    Json(request): Json<RegistrationRequest>,
+) -> Response {
+    if let Some(response) = simulate(&state, "register").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    if let Some(err) = registration_error(&state, &request) {
+        return error(ApiError::RegistrationError(err));
+    }
+    state.users.push(User {
+        user_name: request.user_name,
+        mail_addr: request.mail_addr,
+        pass_hash: request.pass_hash,
+        accepted_terms: request.terms_version,
+        deletion_scheduled_for: None,
+    });
+    Json(success("register")).into_response()
+}
+
+#[derive(Deserialize)]
+struct PasswordResetRequest {
+    user_info: String,
+}
+
+async fn reset_password(
+    State(state): State<SharedState>,
+    Json(request): Json<PasswordResetRequest>,
+) -> Response {
+    if let Some(response) = simulate(&state, "reset_password").await {
+        return response;
+    }
+    let state = state.lock().unwrap();
+    let err = if request.user_info.is_empty() {
+        PasswordResetError::EmptyUserInfo
+    } else if state.find_user(&request.user_info).is_none() {
+        PasswordResetError::AccountDoesNotExist
+    } else {
+        return Json(success("reset_password")).into_response();
+    };
+    error(ApiError::PasswordResetError(err))
+}
+
+async fn refresh(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "refresh").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let token = refresh_token(&headers).filter(|token| state.sessions.contains_key(token));
+    let Some(token) = token else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let access_token = state.new_token();
+    state.access_tokens.insert(access_token.clone(), token);
+    Json(serde_json::json!({ "access_token": access_token })).into_response()
+}
+
+async fn logout(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "logout").await {
+        return response;
+    }
+    if let Some(token) = refresh_token(&headers) {
+        state.lock().unwrap().sessions.remove(&token);
+    }
+    let cookie = format!(
+        "{}=; HttpOnly; Path=/api; SameSite=Lax; Max-Age=0",
+        REFRESH_COOKIE
+    );
+    ([(SET_COOKIE, cookie)], StatusCode::OK).into_response()
+}
+
+async fn list_sessions(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "sessions").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((current, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let sessions: Vec<_> = state
+        .sessions
+        .iter()
+        .filter(|(_, session)| session.user_name == user_name)
+        .map(|(token, session)| {
+            let (device, browser) = describe_user_agent(&session.user_agent);
+            serde_json::json!({
+                "id": session.id,
+                "device": device,
+                "browser": browser,
+                "ip_addr": session.ip_addr,
+                "last_seen": timestamp(session.last_seen),
+                "is_current": *token == current,
+            })
+        })
+        .collect();
+    Json(sessions).into_response()
+}
+
+async fn revoke_session(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    if let Some(response) = simulate(&state, "revoke_session").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let count = state.sessions.len();
+    state
+        .sessions
+        .retain(|_, session| !(session.id == id && session.user_name == user_name));
+    match state.sessions.len() < count {
+        true => StatusCode::NO_CONTENT.into_response(),
+        false => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn revoke_all_sessions(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "revoke_all").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    state
+        .sessions
+        .retain(|_, session| session.user_name != user_name);
+    StatusCode::NO_CONTENT.into_response()
+}
+
+#[derive(Deserialize)]
+struct DeletionRequest {
+    pass_hash: String,
+    user_name: String,
+}
+
+async fn delete_account(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(request): Json<DeletionRequest>,
+) -> Response {
+    if let Some(response) = simulate(&state, "account_delete").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let Some(user) = state.find_user_mut(&user_name) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    // A wrong password and a wrong username are reported alike, as with the login.
+    if user.pass_hash != request.pass_hash || user.user_name != request.user_name {
+        let err = ApiError::LoginError(LoginError::InvalidLoginCredentials);
+        return account_error(StatusCode::FORBIDDEN, err);
+    }
+    let scheduled_for = SystemTime::now() + DELETION_GRACE_PERIOD;
+    user.deletion_scheduled_for = Some(scheduled_for);
+    Json(serde_json::json!({ "scheduled_for": timestamp(scheduled_for) })).into_response()
+}
+
+async fn pending_deletion(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "deletion").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let scheduled_for = state
+        .find_user_mut(&user_name)
+        .and_then(|user| user.deletion_scheduled_for);
+    match scheduled_for {
+        Some(scheduled_for) => {
+            Json(serde_json::json!({ "scheduled_for": timestamp(scheduled_for) })).into_response()
+        }
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn undo_deletion(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "deletion").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    if let Some(user) = state.find_user_mut(&user_name) {
+        user.deletion_scheduled_for = None;
+    }
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn request_export(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "export").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let job_id = state.new_token();
+    let job = ExportJob {
+        user_name,
+        started: Instant::now(),
+    };
+    state.exports.insert(job_id.clone(), job);
+    Json(serde_json::json!({ "job_id": job_id })).into_response()
+}
+
+async fn export_status(
+    State(state): State<SharedState>,
+    Path(job_id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    if let Some(response) = simulate(&state, "export").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let Some(job) = state
+        .exports
+        .get(&job_id)
+        .filter(|job| job.user_name == user_name)
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let progress = job.started.elapsed().as_secs_f64() / EXPORT_DURATION.as_secs_f64();
+    let status = match progress < 1. {
+        true => serde_json::json!({ "status": "running", "progress": progress }),
+        false => serde_json::json!({ "status": "done" }),
+    };
+    Json(status).into_response()
+}
+
+async fn download_export(
+    State(state): State<SharedState>,
+    Path(job_id): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    if let Some(response) = simulate(&state, "export").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let is_done = state
+        .exports
+        .get(&job_id)
+        .is_some_and(|job| job.user_name == user_name && job.started.elapsed() >= EXPORT_DURATION);
+    let user = state.find_user_mut(&user_name).cloned();
+    let (true, Some(user)) = (is_done, user) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let sessions: Vec<_> = state
+        .sessions
+        .values()
+        .filter(|session| session.user_name == user_name)
+        .map(|session| {
+            serde_json::json!({
+                "user_agent": session.user_agent,
+                "ip_addr": session.ip_addr,
+                "last_seen": timestamp(session.last_seen),
+            })
+        })
+        .collect();
+    Json(serde_json::json!({
+        "user_name": user.user_name,
+        "mail_addr": user.mail_addr,
+        "accepted_terms": user.accepted_terms,
+        "sessions": sessions,
+    }))
+    .into_response()
+}
+
+async fn terms_status(State(state): State<SharedState>, headers: HeaderMap) -> Response {
+    if let Some(response) = simulate(&state, "terms").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let accepted_version = state
+        .find_user_mut(&user_name)
+        .and_then(|user| user.accepted_terms.clone());
+    Json(serde_json::json!({ "accepted_version": accepted_version })).into_response()
+}
+
+#[derive(Deserialize)]
+struct TermsRequest {
+    terms_version: String,
+}
+
+async fn accept_terms(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(request): Json<TermsRequest>,
+) -> Response {
+    if let Some(response) = simulate(&state, "terms").await {
+        return response;
+    }
+    let mut state = state.lock().unwrap();
+    let Some((_, user_name)) = state.authorize(&headers) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    if let Some(user) = state.find_user_mut(&user_name) {
+        user.accepted_terms = Some(request.terms_version);
+    }
+    StatusCode::NO_CONTENT.into_response()
+}
+
+#[derive(Deserialize)]
+struct BrokenLinkReport {
+    path: String,
+    referrer: String,
+}
+
+async fn report_broken_link(
+    State(state): State<SharedState>,
+    Json(report): Json<BrokenLinkReport>,
+) -> Response {
+    if let Some(response) = simulate(&state, "broken_link").await {
+        return response;
+    }
+    println!("broken link to {} from {}", report.path, report.referrer);
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn set_failure(
+    State(state): State<SharedState>,
+    Path(endpoint): Path<String>,
+    Json(failure): Json<Failure>,
+) -> StatusCode {
+    state.lock().unwrap().failures.insert(endpoint, failure);
+    StatusCode::NO_CONTENT
+}
+
+async fn clear_failure(
+    State(state): State<SharedState>,
+    Path(endpoint): Path<String>,
+) -> StatusCode {
+    state.lock().unwrap().failures.remove(&endpoint);
+    StatusCode::NO_CONTENT
+}
+
+async fn set_latency(State(state): State<SharedState>, Json(millis): Json<u64>) -> StatusCode {
+    state.lock().unwrap().latency = Duration::from_millis(millis);
+    StatusCode::NO_CONTENT
+}
+
+async fn reset(State(state): State<SharedState>) -> StatusCode {
+    *state.lock().unwrap() = MockState::default();
+    StatusCode::NO_CONTENT
+}
+
+#[tokio::main]
+async fn main() {
+    let address = env::var("ADDRESS").unwrap_or_else(|_| "127.0.0.1:8000".to_string());
+    let client_origin =
+        env::var("CLIENT_ORIGIN").unwrap_or_else(|_| "http://localhost:8080".to_string());
+    let latency = env::var("MOCK_LATENCY_MS")
+        .ok()
+        .and_then(|millis| millis.parse().ok())
+        .map_or(Duration::ZERO, Duration::from_millis);
+    let state = Arc::new(Mutex::new(MockState {
+        latency,
+        ..Default::default()
+    }));
+
+    // The client sends the refresh cookie along, so its origin has to be named explicitly.
+    let cors = CorsLayer::new()
+        .allow_origin(
+            client_origin
+                .parse::<HeaderValue>()
+                .expect("invalid client origin"),
+        )
+        .allow_credentials(true)
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_headers([CONTENT_TYPE, AUTHORIZATION])
+        .expose_headers([RETRY_AFTER]);
+
+    let api = Router::new()
+        .route("/login", post(login))
+        .route("/register", post(register))
+        .route("/reset_password", post(reset_password))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
+        .route("/sessions", get(list_sessions))
+        .route("/sessions/:id", delete(revoke_session))
+        .route("/sessions/revoke_all", post(revoke_all_sessions))
+        .route("/account/delete", post(delete_account))
+        .route(
+            "/account/deletion",
+            get(pending_deletion).delete(undo_deletion),
+        )
+        .route("/account/export", post(request_export))
+        .route("/account/export/:job_id", get(export_status))
+        .route("/account/export/:job_id/download", get(download_export))
+        .route("/account/terms", get(terms_status).post(accept_terms))
+        .route("/reports/broken_link", post(report_broken_link));
+    // Scripting of the mock itself, e.g. `PUT /mock/failures/login` with
+    // `{"throttled": {"retry_after": 30}}` or `{"error": <ApiError>}`.
+    let mock = Router::new()
+        .route(
+            "/failures/:endpoint",
+            put(set_failure).delete(clear_failure),
+        )
+        .route("/latency", put(set_latency))
+        .route("/reset", post(reset));
+    let app = Router::new()
+        .nest("/api", api)
+        .nest("/mock", mock)
+        .with_state(state)
+        .layer(cors);
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .expect("failed to bind address");
+    println!("mock backend listening on {}", address);
+    // The address of each client is listed with its sessions.
+    let app = app.into_make_service_with_connect_info::<SocketAddr>();
+    axum::serve(listener, app).await.expect("server error");
+}
@@ -0,0 +1,58 @@
+use futures::executor::block_on;
+use std::{env, fs, path::Path};
+use yew_client::server::{self, Template};
+use yew_client::services::{i18n::Locale, routing::Route};
+use yew_router::Routable;
+
+// The pages linked from the header and footer look the same for every visitor, the user
+// menu and everything else is left to the client.
+fn public_routes() -> Vec<Route> {
+    Locale::ALL
+        .into_iter()
+        .flat_map(Route::localized)
+        .filter(|route| route.placement().is_some())
+        .collect()
+}
+
+// Each page is written as the index of its directory, which static file servers pick up
+// for paths without a trailing slash.
+fn output_path(dist_dir: &Path, route_path: &str) -> std::path::PathBuf {
+    dist_dir
+        .join(route_path.trim_start_matches('/'))
+        .join("index.html")
+}
+
+fn main() {
+    let dist_dir = env::var("DIST_DIR").unwrap_or_else(|_| "dist".to_string());
+    let dist_dir = Path::new(&dist_dir);
+    // Used for canonical and alternate URLs, which have to be absolute.
+    let origin = env::var("PUBLIC_ORIGIN").unwrap_or_else(|_| "http://localhost:8080".to_string());
+
+    let index_html = fs::read_to_string(dist_dir.join("index.html"))
+        .expect("failed to read index.html, run `trunk build --features hydration` first");
+    let template = Template::parse(&index_html);
+    let render = |path: &str| {
+        let (_, page) = block_on(server::render_page(
+            &template,
+            &origin,
+            path,
+            Default::default(),
+        ));
+        page
+    };
+
+    for route in public_routes() {
+        let path = route.to_path();
+        let output = output_path(dist_dir, &path);
+        fs::create_dir_all(output.parent().unwrap()).expect("failed to create directory");
+        fs::write(&output, render(&path)).expect("failed to write page");
+        println!("{} -> {}", path, output.display());
+    }
+
+    // Static hosts serve `404.html` for unknown paths, its links and assets have to be
+    // absolute, which trunk's output already is.
+    let not_found = format!("/{}{}", Locale::default(), Route::NotFound.to_path());
+    let output = dist_dir.join("404.html");
+    fs::write(&output, render(&not_found)).expect("failed to write page");
+    println!("{} -> {}", not_found, output.display());
+}
@@ -0,0 +1,84 @@
+use axum::{
+    extract::{Query, State},
+    http::{header::ACCEPT_LANGUAGE, HeaderMap, StatusCode, Uri},
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::get,
+    Router,
+};
+use std::{collections::HashMap, env, sync::Arc};
+use tower_http::services::ServeDir;
+use yew_client::server::{self, Template};
+use yew_client::services::{
+    i18n::{self, Locale},
+    routing::Route,
+};
+
+struct ServerState {
+    template: Template,
+    origin: String,
+}
+
+// Browsers list the accepted languages by preference, so the quality values are ignored.
+fn negotiate(headers: &HeaderMap) -> Locale {
+    let accepted = headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let languages = accepted
+        .split(',')
+        .map(|language| language.split(';').next().unwrap_or_default().trim());
+    i18n::negotiate(languages)
+}
+
+async fn render(
+    State(state): State<Arc<ServerState>>,
+    headers: HeaderMap,
+    uri: Uri,
+    Query(queries): Query<HashMap<String, String>>,
+) -> Response {
+    let path = uri.path();
+    if i18n::path_locale(path).is_none() {
+        let path = format!("/{}{}", negotiate(&headers), path.trim_end_matches('/'));
+        let target = match uri.query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path,
+        };
+        return Redirect::temporary(&target).into_response();
+    }
+
+    let (route, page) = server::render_page(&state.template, &state.origin, path, queries).await;
+    let status = match route {
+        Route::NotFound => StatusCode::NOT_FOUND,
+        _ => StatusCode::OK,
+    };
+    (status, Html(page)).into_response()
+}
+
+#[tokio::main]
+async fn main() {
+    let dist_dir = env::var("DIST_DIR").unwrap_or_else(|_| "dist".to_string());
+    let address = env::var("ADDRESS").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
+    // Used for canonical and alternate URLs, which have to be absolute.
+    let origin = env::var("PUBLIC_ORIGIN").unwrap_or_else(|_| format!("http://{}", address));
+
+    let index_html = tokio::fs::read_to_string(format!("{}/index.html", dist_dir))
+        .await
+        .expect("failed to read index.html, run `trunk build --features hydration` first");
+    let state = Arc::new(ServerState {
+        template: Template::parse(&index_html),
+        origin,
+    });
+
+    // Files of the build are served as they are, any other path is rendered by the app.
+    let render = get(render).with_state(state);
+    let files = ServeDir::new(&dist_dir)
+        .append_index_html_on_directories(false)
+        .fallback(render);
+    let app = Router::new().fallback_service(files);
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .expect("failed to bind address");
+    println!("listening on {}", address);
+    axum::serve(listener, app).await.expect("server error");
+}
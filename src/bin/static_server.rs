@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Request, State},
+    http::{
+        header::{
+            CACHE_CONTROL, CONTENT_SECURITY_POLICY, REFERRER_POLICY, STRICT_TRANSPORT_SECURITY,
+            X_CONTENT_TYPE_OPTIONS,
+        },
+        HeaderValue, StatusCode, Uri,
+    },
+    middleware::{self, Next},
+    response::{Html, IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use sha2::{Digest, Sha256};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tower_http::{services::ServeDir, set_header::SetResponseHeaderLayer};
+use yew_client::services::{
+    api::BACKEND_URL,
+    i18n::{self, Locale},
+    routing::Route,
+};
+use yew_router::Routable;
+
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+const HSTS: &str = "max-age=63072000; includeSubDomains";
+
+struct ServerState {
+    dist_dir: PathBuf,
+    index_html: String,
+}
+
+// Paths that the client routes, including those it redirects to a locale.
+fn is_route(path: &str) -> bool {
+    let is_known =
+        |path: &str| Route::recognize(path).is_some_and(|route| route != Route::NotFound);
+    let unprefixed = || {
+        i18n::path_locale(path).is_none() && is_known(&format!("/{}{}", Locale::default(), path))
+    };
+    is_known(path) || unprefixed()
+}
+
+// trunk appends a hash of 16 hex digits to the names of the files it builds.
+fn is_hashed_asset(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or_default();
+    name.split(['-', '_', '.'])
+        .any(|part| part.len() == 16 && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// The inline scripts trunk adds to load the wasm module are allowed by their hashes.
+fn inline_script_hashes(html: &str) -> Vec<String> {
+    let mut hashes = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<script") {
+        rest = &rest[start..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let Some(end) = rest.find("</script>") else {
+            break;
+        };
+        if !rest[..tag_end].contains("src=") && tag_end < end {
+            let digest = Sha256::digest(&rest.as_bytes()[tag_end + 1..end]);
+            hashes.push(format!("'sha256-{}'", BASE64.encode(digest)));
+        }
+        rest = &rest[end..];
+    }
+    hashes
+}
+
+fn content_security_policy(index_html: &str, backend_origin: &str) -> String {
+    format!(
+        "default-src 'self'; script-src 'self' 'wasm-unsafe-eval' {}; \
+         style-src 'self' 'unsafe-inline'; img-src 'self' data:; connect-src 'self' {}; \
+         frame-ancestors 'none'; base-uri 'self'; form-action 'self'",
+        inline_script_hashes(index_html).join(" "),
+        backend_origin
+    )
+}
+
+// Hashed assets never change, anything else has to be revalidated.
+async fn cache_control(request: Request, next: Next) -> Response {
+    let is_hashed = is_hashed_asset(request.uri().path());
+    let mut response = next.run(request).await;
+    let value = match is_hashed && response.status().is_success() {
+        true => IMMUTABLE,
+        false => "no-cache",
+    };
+    response
+        .headers_mut()
+        .insert(CACHE_CONTROL, HeaderValue::from_static(value));
+    response
+}
+
+async fn read_page(path: &Path) -> Option<String> {
+    tokio::fs::read_to_string(path).await.ok()
+}
+
+// Pre-rendered pages are preferred over the bare `index.html`, which the client renders into.
+async fn spa_fallback(State(state): State<Arc<ServerState>>, uri: Uri) -> Response {
+    let path = uri.path();
+    if path.split('/').any(|segment| segment == "..") {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    if !is_route(path) {
+        let page = read_page(&state.dist_dir.join("404.html")).await;
+        let page = page.unwrap_or_else(|| state.index_html.clone());
+        return (StatusCode::NOT_FOUND, Html(page)).into_response();
+    }
+    let prerendered = state
+        .dist_dir
+        .join(path.trim_start_matches('/'))
+        .join("index.html");
+    let page = read_page(&prerendered).await;
+    Html(page.unwrap_or_else(|| state.index_html.clone())).into_response()
+}
+
+#[tokio::main]
+async fn main() {
+    let dist_dir = PathBuf::from(env::var("DIST_DIR").unwrap_or_else(|_| "dist".to_string()));
+    let address = env::var("ADDRESS").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
+
+    let index_html = read_page(&dist_dir.join("index.html"))
+        .await
+        .expect("failed to read index.html, run `trunk build --release` first");
+    // Defaults to the backend the client was built for.
+    let backend_origin = env::var("BACKEND_ORIGIN")
+        .unwrap_or_else(|_| BACKEND_URL.trim_end_matches("/api").to_string());
+    let csp = HeaderValue::from_str(&content_security_policy(&index_html, &backend_origin))
+        .expect("invalid content security policy");
+    let state = Arc::new(ServerState {
+        dist_dir: dist_dir.clone(),
+        index_html,
+    });
+
+    let spa_fallback = get(spa_fallback).with_state(state);
+    let files = ServeDir::new(&dist_dir)
+        .precompressed_br()
+        .precompressed_gzip()
+        .append_index_html_on_directories(false)
+        .fallback(spa_fallback);
+    let header = |name, value| SetResponseHeaderLayer::overriding(name, value);
+    let app = Router::new()
+        .fallback_service(files)
+        .layer(middleware::from_fn(cache_control))
+        .layer(header(CONTENT_SECURITY_POLICY, csp))
+        .layer(header(
+            STRICT_TRANSPORT_SECURITY,
+            HeaderValue::from_static(HSTS),
+        ))
+        .layer(header(
+            REFERRER_POLICY,
+            HeaderValue::from_static("strict-origin-when-cross-origin"),
+        ))
+        .layer(header(
+            X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ));
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .expect("failed to bind address");
+    println!("listening on {}", address);
+    axum::serve(listener, app).await.expect("server error");
+}
@@ -0,0 +1,233 @@
+use crate::services::{
+    api,
+    dom::{self, mark_textfield_validity, set_info_text_error},
+    hashing::generate_hashed_password,
+    i18n::{self, translate_api_message, use_i18n},
+    session::{self, SessionAction, UserContext},
+};
+use fluent::fluent_args;
+use rs_web_api_models::{
+    api_message::{ApiError, ApiMessage, LoginError, RegistrationError},
+    validation::is_valid_mail_addr,
+};
+use serde::Deserialize;
+use stylist::yew::styled_component;
+use wasm_bindgen::JsValue;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Deserialize)]
+struct PendingDeletion {
+    scheduled_for: String,
+}
+
+#[function_component]
+pub fn AccountDeletion() -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let i18n = use_i18n();
+    let is_expanded = use_state(|| false);
+    let typed_name = use_state(String::new);
+
+    if !*is_expanded {
+        let expand = move |_| is_expanded.set(true);
+        return html! {
+            <form>
+                <p class="section">{ i18n.tr("deletion-title") }</p>
+                <input type="button" class="danger" value={ i18n.tr("deletion-expand") }
+                    onclick={ expand } />
+            </form>
+        };
+    }
+
+    // The deletion is only offered once the name of the account has been typed out. Sessions
+    // started with the e-mail address don't know the name, so the backend checks it instead.
+    let account_name = session
+        .user_info
+        .clone()
+        .filter(|user_info| !is_valid_mail_addr(user_info));
+    let is_confirmed = !typed_name.is_empty()
+        && account_name
+            .as_deref()
+            .is_none_or(|account_name| *typed_name == account_name);
+    let confirm_name = {
+        let typed_name = typed_name.clone();
+        let account_name = account_name.clone();
+        move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            let name = input.value();
+            let document = dom::document(dom::window());
+            mark_textfield_validity(
+                &document,
+                "delete_user_name",
+                account_name
+                    .as_deref()
+                    .is_none_or(|account_name| account_name.starts_with(&name)),
+            );
+            typed_name.set(name);
+        }
+    };
+    let cancel = move |_| {
+        typed_name.set(String::new());
+        is_expanded.set(false);
+    };
+    let mismatch = i18n.tr("deletion-user-name-mismatch");
+    let submit = move |_| {
+        let window = dom::window();
+        let document = dom::document(window);
+        let info_text = document.get_element_by_id("delete_info_text").unwrap();
+
+        let pass_word = dom::read_value_from_text_field(&document, "delete_pass_word");
+        let user_name = dom::read_value_from_text_field(&document, "delete_user_name");
+
+        info_text.set_inner_html("");
+        mark_textfield_validity(&document, "delete_pass_word", true);
+        mark_textfield_validity(&document, "delete_user_name", true);
+        if pass_word.is_empty() {
+            let err = ApiError::LoginError(LoginError::EmptyPassWord);
+            let msg = translate_api_message(&ApiMessage::Err(err));
+            mark_textfield_validity(&document, "delete_pass_word", false);
+            set_info_text_error(&info_text, &msg);
+            return;
+        }
+        if user_name.is_empty() {
+            let err = ApiError::RegistrationError(RegistrationError::EmptyUserName);
+            let msg = translate_api_message(&ApiMessage::Err(err));
+            mark_textfield_validity(&document, "delete_user_name", false);
+            set_info_text_error(&info_text, &msg);
+            return;
+        }
+        if account_name
+            .as_deref()
+            .is_some_and(|account_name| user_name != account_name)
+        {
+            mark_textfield_validity(&document, "delete_user_name", false);
+            set_info_text_error(&info_text, &mismatch);
+            return;
+        }
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let pass_hash = generate_hashed_password(&pass_word);
+            let body = serde_json::json!({
+                "pass_hash": pass_hash,
+                "user_name": user_name,
+            });
+            let result = match api::post("/account/delete", &body).await {
+                Ok(result) => result,
+                Err(err) => {
+                    set_info_text_error(&info_text, &err.to_string());
+                    return;
+                }
+            };
+            let status = result.status();
+            if status.is_success() {
+                match result.json().await {
+                    Ok(PendingDeletion { scheduled_for }) => {
+                        let scheduled_for = Some(scheduled_for);
+                        session::dispatch(SessionAction::ScheduleDeletion { scheduled_for });
+                    }
+                    Err(err) => set_info_text_error(&info_text, &err.to_string()),
+                }
+                return;
+            }
+
+            let Ok(api_msg) = result.json::<ApiMessage>().await else {
+                let args = fluent_args!["status" => status.to_string()];
+                set_info_text_error(&info_text, &i18n::tr_args("deletion-failed", &args));
+                return;
+            };
+            let api_msg_str = translate_api_message(&api_msg);
+            set_info_text_error(&info_text, &api_msg_str);
+            if let ApiMessage::Err(ApiError::LoginError(_)) = api_msg {
+                mark_textfield_validity(&document, "delete_pass_word", false);
+            } else {
+                mark_textfield_validity(&document, "delete_user_name", false);
+            }
+            gloo::console::log!(api_msg_str);
+        });
+    };
+
+    html! {
+        <form>
+            <p class="section">{ i18n.tr("deletion-title") }</p>
+            <p>{ i18n.tr("deletion-description") }</p>
+            <input type="password" id="delete_pass_word"
+                placeholder={ i18n.tr("current-pass-word") } /> <br/>
+            <input type="text" id="delete_user_name"
+                placeholder={ i18n.tr("deletion-confirm-user-name") } oninput={ confirm_name } /> <br/>
+            <input type="button" value={ i18n.tr("cancel") } onclick={ cancel } />
+            <input type="button" class="danger" value={ i18n.tr("deletion-title") }
+                disabled={ !is_confirmed } onclick={ submit } />
+            <p id="delete_info_text"></p>
+        </form>
+    }
+}
+
+#[styled_component]
+pub fn PendingDeletionBanner() -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let i18n = use_i18n();
+
+    use_effect_with(session.is_logged_in(), move |is_logged_in| {
+        if *is_logged_in {
+            wasm_bindgen_futures::spawn_local(async {
+                let scheduled_for = fetch_pending_deletion().await;
+                session::dispatch(SessionAction::ScheduleDeletion { scheduled_for });
+            });
+        }
+    });
+
+    let Some(scheduled_for) = session.deletion_scheduled_for.clone() else {
+        return html! {};
+    };
+    let scheduled_for = js_sys::Date::new(&JsValue::from_str(&scheduled_for));
+    if scheduled_for.get_time() < js_sys::Date::now() {
+        return html! {};
+    }
+
+    let undo = move |_| {
+        wasm_bindgen_futures::spawn_local(async {
+            match api::delete("/account/deletion").await {
+                Ok(response) if response.status().is_success() => {
+                    let scheduled_for = None;
+                    session::dispatch(SessionAction::ScheduleDeletion { scheduled_for });
+                }
+                Ok(response) => gloo::console::log!(response.status().to_string()),
+                Err(err) => gloo::console::log!(err.to_string()),
+            }
+        });
+    };
+
+    let class = css!(
+        "
+            padding: var(--space-s);
+            text-align: center;
+            background-color: var(--color-danger);
+            color: var(--color-on-danger);
+
+            input[type=button] {
+                margin-left: var(--space-m);
+                cursor: pointer;
+            }
+        "
+    );
+
+    let locale = i18n.locale.code();
+    let date = scheduled_for.to_locale_date_string(locale, &JsValue::UNDEFINED);
+    let args = fluent_args!["date" => String::from(date)];
+    html! {
+        <div { class } role="alert">
+            { i18n.tr_args("deletion-scheduled", &args) }
+            <input type="button" value={ i18n.tr("deletion-undo") } onclick={ undo } />
+        </div>
+    }
+}
+
+async fn fetch_pending_deletion() -> Option<String> {
+    // The backend responds with 404 if no deletion is pending.
+    let response = api::get("/account/deletion").await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let PendingDeletion { scheduled_for } = response.json().await.ok()?;
+    Some(scheduled_for)
+}
@@ -0,0 +1,147 @@
+use crate::services::{
+    api,
+    i18n::{self, use_i18n},
+    session::{self, SessionAction},
+};
+use fluent::fluent_args;
+use serde::Deserialize;
+use stylist::yew::styled_component;
+use wasm_bindgen::JsValue;
+use yew::prelude::*;
+
+const LOGGED_OUT_EVERYWHERE_NOTICE: &str = "notice-logged-out-everywhere";
+
+#[derive(Clone, Deserialize, PartialEq)]
+pub struct ActiveSession {
+    pub id: String,
+    pub device: String,
+    pub browser: String,
+    pub ip_addr: String,
+    pub last_seen: String,
+    pub is_current: bool,
+}
+
+#[styled_component]
+pub fn ActiveSessions() -> Html {
+    let i18n = use_i18n();
+    let sessions = use_state(|| None::<Result<Vec<ActiveSession>, String>>);
+    let reload = use_state(|| 0_u32);
+
+    {
+        let sessions = sessions.clone();
+        use_effect_with(*reload, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                sessions.set(Some(fetch_sessions().await));
+            });
+        });
+    }
+
+    let revoke = {
+        let reload = reload.clone();
+        move |active_session: ActiveSession| {
+            let reload = reload.clone();
+            Callback::from(move |_| {
+                let reload = reload.clone();
+                let active_session = active_session.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let endpoint = format!("/sessions/{}", active_session.id);
+                    match api::delete(&endpoint).await {
+                        Ok(response) if response.status().is_success() => {
+                            if active_session.is_current {
+                                session::dispatch(SessionAction::LogOut { notice: None });
+                            } else {
+                                reload.set(*reload + 1);
+                            }
+                        }
+                        Ok(response) => gloo::console::log!(response.status().to_string()),
+                        Err(err) => gloo::console::log!(err.to_string()),
+                    }
+                });
+            })
+        }
+    };
+    let log_out_everywhere = move |_| {
+        wasm_bindgen_futures::spawn_local(async {
+            match api::post("/sessions/revoke_all", &()).await {
+                Ok(response) if response.status().is_success() => {
+                    let notice = Some(LOGGED_OUT_EVERYWHERE_NOTICE.to_string());
+                    session::dispatch(SessionAction::LogOut { notice });
+                }
+                Ok(response) => gloo::console::log!(response.status().to_string()),
+                Err(err) => gloo::console::log!(err.to_string()),
+            }
+        });
+    };
+
+    let class = css!(
+        "
+            table {
+                width: 100%;
+                border-collapse: collapse;
+            }
+            td, th {
+                padding: var(--space-xs);
+                text-align: left;
+                border-bottom: 1px solid var(--color-border);
+            }
+            span.current {
+                font-style: italic;
+            }
+        "
+    );
+
+    let list = match &*sessions {
+        None => html! { <p>{ i18n.tr("loading") }</p> },
+        Some(Err(err)) => html! { <p class="error">{ err }</p> },
+        Some(Ok(sessions)) => html! {
+            <table>
+                <tr>
+                    <th>{ i18n.tr("sessions-device") }</th>
+                    <th>{ i18n.tr("sessions-browser") }</th>
+                    <th>{ i18n.tr("sessions-ip-addr") }</th>
+                    <th>{ i18n.tr("sessions-last-seen") }</th>
+                    <th></th>
+                </tr>
+                { for sessions.iter().map(|active_session| html! {
+                    <tr>
+                        <td>{ &active_session.device }</td>
+                        <td>{ &active_session.browser }</td>
+                        <td>{ &active_session.ip_addr }</td>
+                        <td>{ format_date(&active_session.last_seen) }</td>
+                        <td>
+                            if active_session.is_current {
+                                <span class="current">{ i18n.tr("sessions-this-device") }</span>
+                            }
+                            <input type="button" value={ i18n.tr("sessions-revoke") }
+                                onclick={ revoke(active_session.clone()) } />
+                        </td>
+                    </tr>
+                }) }
+            </table>
+        },
+    };
+
+    html! {
+        <form { class }>
+            <p class="section">{ i18n.tr("sessions-title") }</p>
+            { list }
+            <input type="button" value={ i18n.tr("sessions-log-out-everywhere") }
+                onclick={ log_out_everywhere } />
+        </form>
+    }
+}
+
+async fn fetch_sessions() -> Result<Vec<ActiveSession>, String> {
+    let response = api::get("/sessions").await.map_err(|err| err.to_string())?;
+    if !response.status().is_success() {
+        let args = fluent_args!["status" => response.status().to_string()];
+        return Err(i18n::tr_args("sessions-load-failed", &args));
+    }
+    response.json().await.map_err(|err| err.to_string())
+}
+
+fn format_date(date: &str) -> String {
+    let date = js_sys::Date::new(&JsValue::from_str(date));
+    date.to_locale_string(i18n::current_locale().code(), &JsValue::UNDEFINED)
+        .into()
+}
@@ -0,0 +1,144 @@
+use crate::services::{
+    consent::{Consent, ConsentAction, ConsentCategory, ConsentContext},
+    dom,
+    i18n::use_i18n,
+    routing::Route,
+};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[styled_component]
+pub fn ConsentBanner() -> Html {
+    let context = use_context::<ConsentContext>().expect("no `ConsentContext` provided");
+    let i18n = use_i18n();
+    let is_hydrated = dom::use_is_hydrated();
+
+    let class = css!(
+        "
+            position: fixed;
+            left: 0px;
+            right: 0px;
+            bottom: 0px;
+            padding: var(--space-m);
+            background-color: var(--color-surface);
+            border-top: 1px solid var(--color-border);
+
+            .dialog {
+                width: min(100%, max(30%, 25em));
+                margin: auto;
+            }
+            label {
+                display: block;
+                margin-top: var(--space-s);
+            }
+            input[type=button] {
+                height: 2.5em;
+                margin: var(--space-xs);
+                border: none;
+                border-radius: var(--radius-m);
+                outline: 1px solid var(--color-border);
+                font-size: var(--font-size-base);
+                cursor: pointer;
+                background-color: var(--color-surface);
+                color: var(--color-text);
+            }
+            input[type=button].submit {
+                background-color: var(--color-primary);
+                color: var(--color-on-primary);
+            }
+        "
+    );
+
+    let accept_all = {
+        let context = context.clone();
+        move |_| context.dispatch(ConsentAction::Save(Consent::new(true, true, true)))
+    };
+    let accept_essential = {
+        let context = context.clone();
+        move |_| context.dispatch(ConsentAction::Save(Consent::new(false, false, false)))
+    };
+
+    if context.is_editing {
+        let current = context.consent.clone().unwrap_or_default();
+        let save = {
+            let context = context.clone();
+            move |_| {
+                let window = dom::window();
+                let document = dom::document(window);
+                let consent = Consent::new(
+                    dom::read_checkbox(&document, "consent_remember_me"),
+                    dom::read_checkbox(&document, "consent_preferences"),
+                    dom::read_checkbox(&document, "consent_analytics"),
+                );
+                context.dispatch(ConsentAction::Save(consent));
+            }
+        };
+        let close = {
+            let context = context.clone();
+            move |_| context.dispatch(ConsentAction::Close)
+        };
+        let checkbox = |id: &str, category: ConsentCategory, label: String| {
+            html! {
+                <label>
+                    <input type="checkbox" id={ id.to_string() }
+                        checked={ current.is_granted(category) } />
+                    { label }
+                </label>
+            }
+        };
+
+        return html! {
+            <div { class }>
+                <div class="dialog" role="dialog" aria-modal="true">
+                    <p><b>{ i18n.tr("consent-settings-title") }</b></p>
+                    <label>
+                        <input type="checkbox" checked=true disabled=true />
+                        { i18n.tr("consent-essential") }
+                    </label>
+                    { checkbox(
+                        "consent_remember_me",
+                        ConsentCategory::RememberMe,
+                        i18n.tr("consent-remember-me"),
+                    ) }
+                    { checkbox(
+                        "consent_preferences",
+                        ConsentCategory::Preferences,
+                        i18n.tr("consent-preferences"),
+                    ) }
+                    { checkbox(
+                        "consent_analytics",
+                        ConsentCategory::Analytics,
+                        i18n.tr("consent-analytics"),
+                    ) }
+                    <input type="button" value={ i18n.tr("cancel") } onclick={ close } />
+                    <input type="button" value={ i18n.tr("consent-essential-only") }
+                        onclick={ accept_essential } />
+                    <input type="button" class="submit" value={ i18n.tr("save") } onclick={ save } />
+                </div>
+            </div>
+        };
+    }
+
+    if !is_hydrated || context.consent.is_some() {
+        return html! {};
+    }
+
+    let edit = move |_| context.dispatch(ConsentAction::Edit);
+    html! {
+        <div { class }>
+            <div class="dialog" role="dialog">
+                <p>
+                    { i18n.tr("consent-description") }
+                    { " " }
+                    <Link<Route> to={ Route::Privacy { locale: i18n.locale } }>{ i18n.tr("legal-privacy-policy") }</Link<Route>>
+                </p>
+                <input type="button" value={ i18n.tr("consent-settings") } onclick={ edit } />
+                <input type="button" value={ i18n.tr("consent-essential-only") }
+                    onclick={ accept_essential } />
+                <input type="button" class="submit" value={ i18n.tr("consent-accept-all") }
+                    onclick={ accept_all } />
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,148 @@
+use crate::services::{
+    api, dom,
+    i18n::{self, use_i18n},
+};
+use fluent::{fluent_args, FluentArgs};
+use gloo::{
+    file::{Blob, ObjectUrl},
+    timers::{callback::Timeout, future::TimeoutFuture},
+};
+use reqwest::header::CONTENT_TYPE;
+use serde::Deserialize;
+use yew::prelude::*;
+
+const POLL_INTERVAL_MILLIS: u32 = 2_000;
+
+#[derive(Deserialize)]
+struct ExportJob {
+    job_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum ExportStatus {
+    Pending,
+    Running { progress: f64 },
+    Done,
+    Failed { reason: String },
+}
+
+#[derive(Clone, PartialEq)]
+enum ExportState {
+    Idle,
+    Running(f64),
+    Done,
+    Failed(String),
+}
+
+#[function_component]
+pub fn DataExport() -> Html {
+    let i18n = use_i18n();
+    let state = use_state(|| ExportState::Idle);
+
+    let is_busy = matches!(*state, ExportState::Running(_));
+    let export = {
+        let state = state.clone();
+        move |_| {
+            let state = state.clone();
+            state.set(ExportState::Running(0.));
+            wasm_bindgen_futures::spawn_local(async move {
+                let on_progress = {
+                    let state = state.clone();
+                    move |progress| state.set(ExportState::Running(progress))
+                };
+                let result = export_personal_data(on_progress).await;
+                match result {
+                    Ok(()) => state.set(ExportState::Done),
+                    Err(err) => state.set(ExportState::Failed(err)),
+                }
+            });
+        }
+    };
+
+    let status = match &*state {
+        ExportState::Idle => html! {},
+        ExportState::Running(progress) => html! {
+            <>
+                <progress max="100" value={ format!("{:.0}", progress * 100.) } />
+                <p>{ i18n.tr_args("export-progress", &progress_args(*progress)) }</p>
+            </>
+        },
+        ExportState::Done => html! { <p class="success">{ i18n.tr("export-done") }</p> },
+        ExportState::Failed(err) => html! { <p class="error">{ err }</p> },
+    };
+
+    html! {
+        <form>
+            <p class="section">{ i18n.tr("export-title") }</p>
+            <p>{ i18n.tr("export-description") }</p>
+            <input type="button" value={ i18n.tr("export-title") }
+                disabled={ is_busy } onclick={ export } />
+            { status }
+        </form>
+    }
+}
+
+fn progress_args(progress: f64) -> FluentArgs<'static> {
+    fluent_args!["percent" => format!("{:.0}", progress * 100.)]
+}
+
+fn status_error(key: &str, response: &reqwest::Response) -> String {
+    let args = fluent_args!["status" => response.status().to_string()];
+    i18n::tr_args(key, &args)
+}
+
+async fn export_personal_data(on_progress: impl Fn(f64)) -> Result<(), String> {
+    let response = api::post("/account/export", &())
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(status_error("export-request-failed", &response));
+    }
+    let ExportJob { job_id } = response.json().await.map_err(|e| e.to_string())?;
+
+    loop {
+        let endpoint = format!("/account/export/{}", job_id);
+        let response = api::get(&endpoint).await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(status_error("export-query-failed", &response));
+        }
+        match response.json().await.map_err(|e| e.to_string())? {
+            ExportStatus::Pending => on_progress(0.),
+            ExportStatus::Running { progress } => on_progress(progress),
+            ExportStatus::Done => break,
+            ExportStatus::Failed { reason } => return Err(reason),
+        }
+        TimeoutFuture::new(POLL_INTERVAL_MILLIS).await;
+    }
+
+    let endpoint = format!("/account/export/{}/download", job_id);
+    let response = api::get(&endpoint).await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(status_error("export-download-failed", &response));
+    }
+    let mime_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/json")
+        .to_string();
+    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+
+    let extension = match mime_type.as_str() {
+        "application/zip" => "zip",
+        _ => "json",
+    };
+    let file_name = format!("personal-data-export.{}", extension);
+    let blob = Blob::new_with_options(&bytes[..], Some(&mime_type));
+    let object_url = ObjectUrl::from(blob);
+
+    let window = dom::window();
+    let document = dom::document(window);
+    dom::download(&document, &object_url, &file_name);
+
+    // The object URL is revoked once dropped, which must not happen before the browser
+    // has started the download.
+    Timeout::new(60_000, move || drop(object_url)).forget();
+    Ok(())
+}
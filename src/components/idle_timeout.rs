@@ -0,0 +1,201 @@
+use crate::services::{api, dom, i18n::use_i18n, session::UserContext};
+use fluent::fluent_args;
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+    timers::callback::Interval,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+const IDLE_TIMEOUT_SECS: f64 = 15. * 60.;
+// Remembered sessions are meant to outlive the browser, they are only ended after a day
+// without any activity in an open tab.
+const REMEMBERED_IDLE_TIMEOUT_SECS: f64 = 24. * 60. * 60.;
+const WARNING_SECS: f64 = 60.;
+const ACTIVITY_EVENTS: [&str; 5] = ["pointermove", "pointerdown", "keydown", "wheel", "scroll"];
+const IDLE_NOTICE: &str = "notice-idle-logout";
+// Shared by all tabs, so that a session used in one of them is not logged out by another.
+// It is strictly necessary and therefore kept regardless of consent.
+const LAST_ACTIVITY_KEY: &str = "yew-client-last-activity";
+const ACTIVITY_WRITE_SECS: f64 = 1.;
+// The last activity of the session that was logged out for being idle, so that only the
+// first tab to notice logs it out and the others follow the broadcast `LogOut`.
+const IDLE_LOGOUT_KEY: &str = "yew-client-idle-logout";
+
+fn now_secs() -> f64 {
+    js_sys::Date::now() / 1000.
+}
+
+fn record_activity(last_activity: &RefCell<f64>) {
+    let now = now_secs();
+    *last_activity.borrow_mut() = now;
+    if let Err(err) = LocalStorage::set(LAST_ACTIVITY_KEY, now) {
+        gloo::console::log!(err.to_string());
+    }
+}
+
+fn claim_idle_logout(last_activity: f64) -> bool {
+    if LocalStorage::get::<f64>(IDLE_LOGOUT_KEY).is_ok_and(|claimed| claimed == last_activity) {
+        return false;
+    }
+    if let Err(err) = LocalStorage::set(IDLE_LOGOUT_KEY, last_activity) {
+        gloo::console::log!(err.to_string());
+    }
+    true
+}
+
+// The latest activity in any tab.
+fn newest_activity(last_activity: &RefCell<f64>) -> f64 {
+    let shared = LocalStorage::get::<f64>(LAST_ACTIVITY_KEY).unwrap_or_default();
+    let mut last_activity = last_activity.borrow_mut();
+    *last_activity = last_activity.max(shared);
+    *last_activity
+}
+
+#[styled_component]
+pub fn IdleTimeoutDialog() -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let i18n = use_i18n();
+    // Set once tracking starts, the clock is not available while rendering on the server.
+    let last_activity = use_mut_ref(|| 0.);
+    let remaining_secs = use_state_eq(|| None::<u32>);
+
+    {
+        let last_activity = last_activity.clone();
+        let remaining_secs = remaining_secs.clone();
+        let idle_timeout_secs = match session.remember_me {
+            true => REMEMBERED_IDLE_TIMEOUT_SECS,
+            false => IDLE_TIMEOUT_SECS,
+        };
+        let tracked_timeout = session.is_logged_in().then_some(idle_timeout_secs);
+        use_effect_with(tracked_timeout, move |tracked_timeout| {
+            let mut listeners = Vec::new();
+            let mut interval = None;
+
+            if let Some(idle_timeout_secs) = *tracked_timeout {
+                record_activity(&last_activity);
+
+                let window = dom::window();
+                for event_type in ACTIVITY_EVENTS {
+                    let last_activity = last_activity.clone();
+                    let listener = EventListener::new(&window, event_type, move |_| {
+                        // Once the warning is displayed, only the dialog can extend the session.
+                        // Otherwise the activity is written at most once a second.
+                        let idle_secs = now_secs() - *last_activity.borrow();
+                        if (ACTIVITY_WRITE_SECS..idle_timeout_secs - WARNING_SECS)
+                            .contains(&idle_secs)
+                        {
+                            record_activity(&last_activity);
+                        }
+                    });
+                    listeners.push(listener);
+                }
+
+                let has_expired = Rc::new(Cell::new(false));
+                let remaining_secs = remaining_secs.clone();
+                interval = Some(Interval::new(1_000, move || {
+                    if has_expired.get() {
+                        return;
+                    }
+                    let active_at = newest_activity(&last_activity);
+                    let idle_secs = now_secs() - active_at;
+                    if idle_secs >= idle_timeout_secs {
+                        has_expired.set(true);
+                        remaining_secs.set(None);
+                        if claim_idle_logout(active_at) {
+                            wasm_bindgen_futures::spawn_local(api::log_out(Some(IDLE_NOTICE)));
+                        }
+                    } else if idle_secs >= idle_timeout_secs - WARNING_SECS {
+                        let secs = (idle_timeout_secs - idle_secs).ceil() as u32;
+                        remaining_secs.set(Some(secs));
+                    } else {
+                        // The session was extended in another tab.
+                        remaining_secs.set(None);
+                    }
+                }));
+            } else {
+                remaining_secs.set(None);
+            }
+
+            move || {
+                drop(listeners);
+                drop(interval);
+            }
+        });
+    }
+
+    let Some(secs) = *remaining_secs else {
+        return html! {};
+    };
+
+    let extend_session = {
+        let last_activity = last_activity.clone();
+        let remaining_secs = remaining_secs.clone();
+        move |_| {
+            record_activity(&last_activity);
+            remaining_secs.set(None);
+            wasm_bindgen_futures::spawn_local(async {
+                api::extend_session().await;
+            });
+        }
+    };
+    let log_out = move |_| {
+        remaining_secs.set(None);
+        wasm_bindgen_futures::spawn_local(api::log_out(None));
+    };
+
+    let class = css!(
+        "
+            position: fixed;
+            inset: 0px;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            background-color: var(--color-overlay);
+
+            .dialog {
+                width: min(90%, 25em);
+                padding: var(--space-m);
+                border-radius: var(--radius-m);
+                background-color: var(--color-surface);
+                text-align: center;
+            }
+            p.title {
+                font-size: var(--font-size-section);
+            }
+            input[type=button] {
+                width: calc(50% - 0.5em);
+                height: 2.5em;
+                margin: var(--space-xs);
+                border: none;
+                border-radius: var(--radius-m);
+                outline: 1px solid var(--color-border);
+                font-size: var(--font-size-base);
+                cursor: pointer;
+                background-color: var(--color-surface);
+                color: var(--color-text);
+            }
+            input[type=button].submit {
+                background-color: var(--color-primary);
+                color: var(--color-on-primary);
+            }
+        "
+    );
+
+    html! {
+        <div { class }>
+            <div class="dialog" role="alertdialog" aria-modal="true">
+                <p class="title">{ i18n.tr("idle-title") }</p>
+                <p>{ i18n.tr_args("idle-countdown", &fluent_args!["secs" => secs]) }</p>
+                <input type="button" value={ i18n.tr("log-out") } onclick={ log_out } />
+                <input type="button" class="submit" value={ i18n.tr("idle-stay-logged-in") }
+                    onclick={ extend_session } />
+            </div>
+        </div>
+    }
+}
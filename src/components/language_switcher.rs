@@ -0,0 +1,53 @@
+use crate::services::{
+    i18n::{use_i18n, Locale},
+    routing::Route,
+};
+use stylist::yew::styled_component;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[styled_component]
+pub fn LanguageSwitcher() -> Html {
+    let i18n = use_i18n();
+    let route = use_route::<Route>();
+    let navigator = use_navigator();
+
+    let class = css!(
+        "
+            font-size: var(--font-size-base);
+            padding: var(--space-xs);
+            border-radius: var(--radius-m);
+        "
+    );
+
+    let onchange = {
+        let i18n = i18n.clone();
+        move |event: Event| {
+            let select: HtmlSelectElement = event.target_unchecked_into();
+            let Ok(locale) = select.value().parse::<Locale>() else {
+                return;
+            };
+            // Localized pages switch by URL, so that the choice is kept in the history.
+            match (&route, &navigator) {
+                (Some(route), Some(navigator)) if route.locale().is_some() => {
+                    navigator.push(&route.with_locale(locale))
+                }
+                _ => i18n.set_locale(locale),
+            }
+        }
+    };
+    let options = Locale::ALL.into_iter().map(|locale| {
+        html! {
+            <option value={ locale.code() } lang={ locale.code() } selected={ locale == i18n.locale }>
+                { locale.name() }
+            </option>
+        }
+    });
+
+    html! {
+        <select { class } aria-label={ i18n.tr("language") } { onchange }>
+            { for options }
+        </select>
+    }
+}
@@ -0,0 +1,183 @@
+use crate::components::{language_switcher::LanguageSwitcher, theme_toggle::ThemeToggle};
+use crate::services::{
+    api,
+    consent::{ConsentAction, ConsentContext},
+    i18n::use_i18n,
+    routing::{Placement, Route},
+    session::UserContext,
+};
+use stylist::yew::styled_component;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LayoutProps {
+    pub children: Html,
+}
+
+#[styled_component]
+pub fn Layout(props: &LayoutProps) -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let consent = use_context::<ConsentContext>();
+    let i18n = use_i18n();
+    let route = use_route::<Route>();
+    let is_menu_open = use_state_eq(|| false);
+
+    // The menu is closed again once one of its links has been followed.
+    {
+        let is_menu_open = is_menu_open.clone();
+        use_effect_with(route.clone(), move |_| is_menu_open.set(false));
+    }
+
+    let class = css!(
+        "
+            display: flex;
+            flex-direction: column;
+            min-height: 100vh;
+
+            header {
+                display: flex;
+                flex-wrap: wrap;
+                align-items: center;
+                gap: var(--space-m);
+                padding: var(--space-s) var(--space-m);
+                border-bottom: 1px solid var(--color-border);
+            }
+            header .logo {
+                font-size: var(--font-size-section);
+                font-weight: bold;
+                text-decoration: none;
+                color: var(--color-text);
+            }
+            header nav {
+                display: flex;
+                flex: 1;
+                align-items: center;
+                gap: var(--space-m);
+            }
+            header nav .spacer {
+                flex: 1;
+            }
+            header nav a.active {
+                font-weight: bold;
+            }
+            header input[type=button] {
+                font-size: var(--font-size-base);
+                cursor: pointer;
+                border: none;
+                border-radius: var(--radius-m);
+                outline: 1px solid var(--color-border);
+                background-color: var(--color-surface);
+                color: var(--color-text);
+            }
+            header .menu {
+                display: none;
+                margin-left: auto;
+            }
+
+            main {
+                flex: 1;
+            }
+
+            footer {
+                display: flex;
+                flex-wrap: wrap;
+                justify-content: center;
+                gap: var(--space-m);
+                padding: var(--space-m);
+                border-top: 1px solid var(--color-border);
+                color: var(--color-muted);
+            }
+            footer button.consent {
+                padding: 0px;
+                border: none;
+                background: none;
+                font: inherit;
+                color: var(--color-link);
+                cursor: pointer;
+                text-decoration: underline;
+            }
+
+            @media (max-width: 40em) {
+                header .menu {
+                    display: block;
+                }
+                header nav {
+                    display: none;
+                    flex-basis: 100%;
+                    flex-direction: column;
+                    align-items: flex-start;
+                }
+                header nav.open {
+                    display: flex;
+                }
+            }
+        "
+    );
+
+    let link = |target: Route| {
+        let classes = classes!((Some(&target) == route.as_ref()).then_some("active"));
+        let title = i18n.tr(target.title_key());
+        html! { <Link<Route> { classes } to={ target }>{ title }</Link<Route>> }
+    };
+    let links = |placement: Placement| {
+        Route::localized(i18n.locale)
+            .into_iter()
+            .filter(move |route| route.placement() == Some(placement))
+            .map(link)
+            .collect::<Html>()
+    };
+
+    let user_menu = match session.user_info.clone() {
+        Some(user_info) if session.is_logged_in() => {
+            let log_out = move |_| wasm_bindgen_futures::spawn_local(api::log_out(None));
+            html! {
+                <>
+                    { link(Route::Account { locale: i18n.locale }) }
+                    <span>{ user_info }</span>
+                    <input type="button" value={ i18n.tr("log-out") } onclick={ log_out } />
+                </>
+            }
+        }
+        _ => link(Route::Login {
+            locale: i18n.locale,
+        }),
+    };
+
+    let toggle_menu = {
+        let is_menu_open = is_menu_open.clone();
+        move |_| is_menu_open.set(!*is_menu_open)
+    };
+    let edit_consent = move |_| {
+        if let Some(consent) = &consent {
+            consent.dispatch(ConsentAction::Edit);
+        }
+    };
+
+    html! {
+        <div { class }>
+            <header>
+                <Link<Route> classes="logo" to={ Route::Home { locale: i18n.locale } }>
+                    { i18n.tr("app-name") }
+                </Link<Route>>
+                <input type="button" class="menu" value={ i18n.tr("menu") }
+                    aria-expanded={ is_menu_open.to_string() } aria-controls="main_navigation"
+                    onclick={ toggle_menu } />
+                <nav id="main_navigation" class={ classes!(is_menu_open.then_some("open")) }>
+                    { links(Placement::Header) }
+                    <span class="spacer"></span>
+                    { user_menu }
+                    <ThemeToggle />
+                    <LanguageSwitcher />
+                </nav>
+            </header>
+            <main>{ props.children.clone() }</main>
+            <footer>
+                { links(Placement::Footer) }
+                <button type="button" class="consent" onclick={ edit_consent }>
+                    { i18n.tr("consent-settings-title") }
+                </button>
+            </footer>
+        </div>
+    }
+}
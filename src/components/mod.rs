@@ -0,0 +1,9 @@
+pub mod account_deletion;
+pub mod active_sessions;
+pub mod consent_banner;
+pub mod data_export;
+pub mod idle_timeout;
+pub mod language_switcher;
+pub mod layout;
+pub mod terms_gate;
+pub mod theme_toggle;
@@ -0,0 +1,118 @@
+use crate::screens::legal::{LegalDocument, TERMS_VERSION};
+use crate::services::{
+    api, dom,
+    i18n::{self, use_i18n},
+    session::{self, SessionAction, UserContext},
+};
+use fluent::fluent_args;
+use serde::Deserialize;
+use stylist::yew::styled_component;
+use yew::prelude::*;
+
+#[derive(Deserialize)]
+struct TermsStatus {
+    accepted_version: Option<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TermsGateProps {
+    pub children: Html,
+}
+
+// Blocks the routed screens with an interstitial until the latest terms are accepted.
+#[styled_component]
+pub fn TermsGate(props: &TermsGateProps) -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let i18n = use_i18n();
+    let error = use_state(|| None::<String>);
+
+    use_effect_with(session.is_logged_in(), move |is_logged_in| {
+        if *is_logged_in {
+            wasm_bindgen_futures::spawn_local(async {
+                let Ok(response) = api::get("/account/terms").await else {
+                    return;
+                };
+                if !response.status().is_success() {
+                    gloo::console::log!(response.status().to_string());
+                    return;
+                }
+                if let Ok(TermsStatus { accepted_version }) = response.json().await {
+                    let has_outdated_terms = accepted_version.as_deref() != Some(TERMS_VERSION);
+                    session::dispatch(SessionAction::ReviewTerms { has_outdated_terms });
+                }
+            });
+        }
+    });
+
+    if !(session.is_logged_in() && session.has_outdated_terms) {
+        return props.children.clone();
+    }
+
+    let accept = {
+        let error = error.clone();
+        move |_| {
+            let window = dom::window();
+            let document = dom::document(window);
+            if !dom::read_checkbox(&document, "accept_updated_terms") {
+                error.set(Some(i18n::tr("terms-not-accepted")));
+                return;
+            }
+
+            let error = error.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let body = serde_json::json!({ "terms_version": TERMS_VERSION });
+                match api::post("/account/terms", &body).await {
+                    Ok(response) if response.status().is_success() => {
+                        let has_outdated_terms = false;
+                        session::dispatch(SessionAction::ReviewTerms { has_outdated_terms });
+                    }
+                    Ok(response) => error.set(Some(response.status().to_string())),
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            });
+        }
+    };
+    let log_out = move |_| wasm_bindgen_futures::spawn_local(api::log_out(None));
+
+    let class = css!(
+        "
+            width: min(100% - 2em, 45em);
+            margin: auto;
+            line-height: var(--line-height);
+
+            article {
+                max-height: 50vh;
+                overflow-y: auto;
+                border: 1px solid var(--color-border);
+                padding: 0 var(--space-m);
+            }
+            input[type=button] {
+                height: 2.5em;
+                margin: var(--space-xs);
+                font-size: var(--font-size-base);
+                cursor: pointer;
+            }
+            p.error {
+                color: var(--color-error);
+            }
+        "
+    );
+
+    html! {
+        <div { class }>
+            <p class="title">{ i18n.tr("terms-updated-title") }</p>
+            <p>{ i18n.tr("terms-updated-description") }</p>
+            <article>{ LegalDocument::TermsOfService.to_html(i18n.locale) }</article>
+            <label>
+                <input type="checkbox" id="accept_updated_terms" />
+                { i18n.tr_args("terms-accept-version", &fluent_args!["version" => TERMS_VERSION]) }
+            </label>
+            <br/>
+            <input type="button" value={ i18n.tr("log-out") } onclick={ log_out } />
+            <input type="button" value={ i18n.tr("terms-accept") } onclick={ accept } />
+            if let Some(error) = &*error {
+                <p class="error">{ error }</p>
+            }
+        </div>
+    }
+}
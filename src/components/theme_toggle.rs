@@ -0,0 +1,44 @@
+use crate::services::{
+    i18n::use_i18n,
+    theme::{ThemeChoice, ThemeContext},
+};
+use stylist::yew::styled_component;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+#[styled_component]
+pub fn ThemeToggle() -> Html {
+    let context = use_context::<ThemeContext>().expect("no `ThemeContext` provided");
+    let i18n = use_i18n();
+
+    let class = css!(
+        "
+            font-size: var(--font-size-base);
+            padding: var(--space-xs);
+            border-radius: var(--radius-m);
+        "
+    );
+
+    let onchange = {
+        let context = context.clone();
+        move |event: Event| {
+            let select: HtmlSelectElement = event.target_unchecked_into();
+            if let Some(choice) = ThemeChoice::from_code(&select.value()) {
+                context.set_choice(choice);
+            }
+        }
+    };
+    let options = ThemeChoice::ALL.into_iter().map(|choice| {
+        html! {
+            <option value={ choice.code() } selected={ choice == context.choice }>
+                { i18n.tr(choice.translation_key()) }
+            </option>
+        }
+    });
+
+    html! {
+        <select { class } aria-label={ i18n.tr("theme") } { onchange }>
+            { for options }
+        </select>
+    }
+}
@@ -1,2 +1,6 @@
+pub mod app;
+pub mod components;
 pub mod screens;
+#[cfg(feature = "ssr")]
+pub mod server;
 pub mod services;
@@ -1,30 +1,19 @@
-use stylist::css;
-use yew::prelude::*;
-use yew_client::services::routing::{switch, Route};
-use yew_router::prelude::*;
-
-const DEFAULT_FONT_FAMILY: &str = "Arial, sans-serif";
-
-#[function_component]
-fn App() -> Html {
-    let class = css!(
-        "
-            --DEFAULT_FONT_FAMILY: ${DEFAULT_FONT_FAMILY};
-
-            font-family: var(--DEFAULT_FONT_FAMILY);
-        ",
-        DEFAULT_FONT_FAMILY = DEFAULT_FONT_FAMILY
-    );
-
-    html! {
-        <div {class}>
-            <BrowserRouter>
-                <Switch<Route> render={switch} />
-            </BrowserRouter>
-        </div>
-    }
-}
+use yew_client::app::App;
+#[cfg(feature = "hydration")]
+use yew_client::services::{dom, routing};
 
 fn main() {
-    yew::Renderer::<App>::new().render();
+    let renderer = yew::Renderer::<App>::new();
+    // Markup rendered for another path, such as a static host's `404.html` served for a
+    // client route, is replaced instead of hydrated.
+    #[cfg(feature = "hydration")]
+    {
+        let path = routing::current_path();
+        if routing::rendered_path().is_some_and(|rendered| rendered == path) {
+            renderer.hydrate();
+            return;
+        }
+        dom::body(&dom::document(dom::window())).set_inner_html("");
+    }
+    renderer.render();
 }
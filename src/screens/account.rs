@@ -0,0 +1,350 @@
+use crate::components::{
+    account_deletion::AccountDeletion, active_sessions::ActiveSessions, data_export::DataExport,
+};
+use crate::services::{
+    api,
+    dom::{self, mark_textfield_validity, set_info_text_error, set_info_text_ok},
+    hashing::generate_hashed_password,
+    head::{use_document_head, DocumentHead},
+    i18n::{self, translate_api_message, use_i18n},
+    routing::Route,
+    session::{self, SessionAction, UserContext},
+};
+use fluent::fluent_args;
+use rs_web_api_models::api_message::{ApiError, ApiMessage, LoginError, RegistrationError};
+use stylist::yew::styled_component;
+use web_sys::Document;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AccountSection {
+    PassWord,
+    MailAddress,
+    UserName,
+}
+
+impl AccountSection {
+    fn endpoint(&self) -> &'static str {
+        match self {
+            AccountSection::PassWord => "/account/pass_word",
+            AccountSection::MailAddress => "/account/mail_addr",
+            AccountSection::UserName => "/account/user_name",
+        }
+    }
+
+    fn info_text_id(&self) -> &'static str {
+        match self {
+            AccountSection::PassWord => "pass_word_info_text",
+            AccountSection::MailAddress => "mail_addr_info_text",
+            AccountSection::UserName => "user_name_info_text",
+        }
+    }
+
+    fn current_pass_word_id(&self) -> &'static str {
+        match self {
+            AccountSection::PassWord => "pass_word_current",
+            AccountSection::MailAddress => "mail_addr_current",
+            AccountSection::UserName => "user_name_current",
+        }
+    }
+
+    fn text_field_ids(&self) -> Vec<&'static str> {
+        let mut ids = vec![self.current_pass_word_id()];
+        match self {
+            AccountSection::PassWord => ids.extend(["new_pass_word", "new_pwconfirm"]),
+            AccountSection::MailAddress => ids.push("new_mail_addr"),
+            AccountSection::UserName => ids.push("new_user_name"),
+        };
+        ids
+    }
+}
+
+#[styled_component]
+pub fn AccountScreen() -> Html {
+    let session = use_context::<UserContext>().expect("no `UserContext` provided");
+    let i18n = use_i18n();
+    let is_hydrated = dom::use_is_hydrated();
+    use_document_head(DocumentHead::new(i18n.tr("account-title")));
+
+    if !session.is_logged_in() {
+        return match !is_hydrated || session::is_restorable() {
+            true => html! { <p>{ i18n.tr("loading") }</p> },
+            false => html! { <Redirect<Route> to={ Route::Login { locale: i18n.locale } } /> },
+        };
+    }
+
+    let class = css!(
+        "
+            --pad: var(--space-s);
+            --background-color: var(--color-primary);
+            --border-color: var(--color-border);
+            --border-radius: var(--radius-m);
+
+            width: 100%;
+            form {
+                width: min(100%, max(30%, 20em));
+                margin: auto;
+                margin-bottom: calc(4 * var(--pad));
+            }
+            p.title {
+                font-size: var(--font-size-title);
+                text-align: center;
+            }
+            p.section {
+                font-size: var(--font-size-section);
+                margin-bottom: 0px;
+            }
+
+            input[type=text], input[type=password], input[type=button] {
+                outline: 1px solid var(--border-color);
+                border-radius: var(--border-radius);
+                border: none;
+                height: 2.5em;
+                font-size: var(--font-size-base);
+                margin-top: var(--pad);
+                margin-bottom: var(--pad);
+            }
+            input[type=text], input[type=password] {
+                background-color: var(--color-surface);
+                color: var(--color-text);
+                width: calc(100% - 3*var(--pad));
+                padding-left: calc(1.5*var(--pad));
+                padding-right: calc(1.5*var(--pad));
+            }
+            input[type=button] {
+                width: 100%;
+                cursor: pointer;
+                background-color: var(--background-color);
+                color: var(--color-on-primary);
+            }
+            input[type=button].danger {
+                background-color: var(--color-danger);
+                color: var(--color-on-danger);
+            }
+            input[type=button]:disabled {
+                cursor: not-allowed;
+                opacity: 0.5;
+            }
+
+            p.success {
+                color: var(--color-success);
+            }
+            p.error {
+                color: var(--color-error);
+            }
+            input[type=text].error, input[type=password].error {
+                outline: 2px solid var(--color-error);
+            }
+        "
+    );
+
+    let user_info = session.user_info.clone().unwrap_or_default();
+
+    html! {
+        <div { class }>
+            <p class="title">{ i18n.tr("account-title") }</p>
+            <form>
+                <p class="section">{ i18n.tr("account-change-pass-word") }</p>
+                <input type="password" id="pass_word_current"
+                    placeholder={ i18n.tr("current-pass-word") } /> <br/>
+                <input type="password" id="new_pass_word"
+                    placeholder={ i18n.tr("account-new-pass-word") } /> <br/>
+                <input type="password" id="new_pwconfirm"
+                    placeholder={ i18n.tr("account-new-pwconfirm") } /> <br/>
+                <input type="button" value={ i18n.tr("account-change-pass-word") }
+                    onclick={ submit(AccountSection::PassWord) } />
+                <p id="pass_word_info_text"></p>
+            </form>
+            <form>
+                <p class="section">{ i18n.tr("account-change-mail-addr") }</p>
+                <input type="password" id="mail_addr_current"
+                    placeholder={ i18n.tr("current-pass-word") } /> <br/>
+                <input type="text" id="new_mail_addr"
+                    placeholder={ i18n.tr("account-new-mail-addr") } /> <br/>
+                <input type="button" value={ i18n.tr("account-change-mail-addr") }
+                    onclick={ submit(AccountSection::MailAddress) } />
+                <p id="mail_addr_info_text"></p>
+            </form>
+            <form>
+                <p class="section">{ i18n.tr("account-change-user-name") }</p>
+                <p>{ i18n.tr_args("account-logged-in-as", &fluent_args!["user_info" => user_info]) }</p>
+                <input type="password" id="user_name_current"
+                    placeholder={ i18n.tr("current-pass-word") } /> <br/>
+                <input type="text" id="new_user_name"
+                    placeholder={ i18n.tr("account-new-user-name") } /> <br/>
+                <input type="button" value={ i18n.tr("account-change-user-name") }
+                    onclick={ submit(AccountSection::UserName) } />
+                <p id="user_name_info_text"></p>
+            </form>
+            <ActiveSessions />
+            <DataExport />
+            <AccountDeletion />
+        </div>
+    }
+}
+
+fn submit(section: AccountSection) -> Callback<MouseEvent> {
+    Callback::from(move |_| {
+        let window = dom::window();
+        let document = dom::document(window);
+        let info_text = document.get_element_by_id(section.info_text_id()).unwrap();
+
+        info_text.set_inner_html("");
+        for id in section.text_field_ids() {
+            mark_textfield_validity(&document, id, true);
+        }
+        if let Some(err) = validate(section, &document) {
+            mark_invalid_fields(section, &document, &err);
+            let msg = translate_api_message(&ApiMessage::Err(err));
+            set_info_text_error(&info_text, &msg);
+            return;
+        }
+
+        let read = |id| dom::read_value_from_text_field(&document, id);
+        let pass_hash = generate_hashed_password(&read(section.current_pass_word_id()));
+        let new_user_name = read("new_user_name");
+        let new_mail_addr = read("new_mail_addr");
+        let body = match section {
+            AccountSection::PassWord => serde_json::json!({
+                "pass_hash": pass_hash,
+                "new_pass_hash": generate_hashed_password(&read("new_pass_word")),
+            }),
+            AccountSection::MailAddress => serde_json::json!({
+                "pass_hash": pass_hash,
+                "mail_addr": &new_mail_addr,
+            }),
+            AccountSection::UserName => serde_json::json!({
+                "pass_hash": pass_hash,
+                "user_name": &new_user_name,
+            }),
+        };
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = match api::post(section.endpoint(), &body).await {
+                Ok(result) => result,
+                Err(err) => {
+                    set_info_text_error(&info_text, &err.to_string());
+                    return;
+                }
+            };
+            let status = result.status();
+            let Ok(api_msg) = result.json::<ApiMessage>().await else {
+                let args = fluent_args!["status" => status.to_string()];
+                set_info_text_error(&info_text, &i18n::tr_args("account-update-failed", &args));
+                return;
+            };
+            let api_msg_str = translate_api_message(&api_msg);
+            match api_msg {
+                ApiMessage::Ok(_msg) => {
+                    // A new e-mail address only takes effect once it has been verified.
+                    let msg = match section {
+                        AccountSection::MailAddress => {
+                            let args = fluent_args!["mail_addr" => new_mail_addr];
+                            let locale = i18n::current_locale();
+                            i18n::translate(locale, "account-verify-mail-addr", Some(&args))
+                        }
+                        _ => api_msg_str.clone(),
+                    };
+                    set_info_text_ok(&info_text, &msg);
+                    for id in section.text_field_ids() {
+                        dom::clear_text_field(&document, id);
+                    }
+                    // Sessions started with the e-mail address keep displaying it.
+                    let is_user_name = session::user_info().is_some_and(|user_info| {
+                        !rs_web_api_models::validation::is_valid_mail_addr(&user_info)
+                    });
+                    if section == AccountSection::UserName && is_user_name {
+                        let user_info = new_user_name;
+                        session::dispatch(SessionAction::Rename { user_info });
+                    }
+                }
+                ApiMessage::Err(err) => {
+                    set_info_text_error(&info_text, &api_msg_str);
+                    mark_invalid_fields(section, &document, &err);
+                }
+            };
+            gloo::console::log!(api_msg_str);
+        });
+    })
+}
+
+fn validate(section: AccountSection, document: &Document) -> Option<ApiError> {
+    let read = |id| dom::read_value_from_text_field(document, id);
+
+    if read(section.current_pass_word_id()).is_empty() {
+        return Some(ApiError::LoginError(LoginError::EmptyPassWord));
+    }
+    let err = match section {
+        AccountSection::PassWord => {
+            let pass_word = read("new_pass_word");
+            let pwconfirm = read("new_pwconfirm");
+            if pass_word.is_empty() {
+                RegistrationError::EmptyPassWord
+            } else if !rs_web_api_models::validation::is_valid_pass_word(&pass_word) {
+                RegistrationError::InvalidPasswordFormat
+            } else if pwconfirm.is_empty() {
+                RegistrationError::EmptyPassWordConfirm
+            } else if pass_word != pwconfirm {
+                RegistrationError::InvalidPasswordConfirmation
+            } else {
+                return None;
+            }
+        }
+        AccountSection::MailAddress => {
+            let mail_addr = read("new_mail_addr");
+            if mail_addr.is_empty() {
+                RegistrationError::EmptyMailAddress
+            } else if !rs_web_api_models::validation::is_valid_mail_addr(&mail_addr) {
+                RegistrationError::InvalidMailAddressFormat
+            } else {
+                return None;
+            }
+        }
+        AccountSection::UserName => {
+            let user_name = read("new_user_name");
+            if user_name.is_empty() {
+                RegistrationError::EmptyUserName
+            } else if !rs_web_api_models::validation::is_valid_user_name(&user_name) {
+                RegistrationError::InvalidUserNameFormat
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(ApiError::RegistrationError(err))
+}
+
+fn mark_invalid_fields(section: AccountSection, document: &Document, err: &ApiError) {
+    let ids = match (section, err) {
+        (_, ApiError::LoginError(_)) => vec![section.current_pass_word_id()],
+        (AccountSection::PassWord, ApiError::RegistrationError(err)) => match err {
+            RegistrationError::EmptyPassWord | RegistrationError::InvalidPasswordFormat => {
+                vec!["new_pass_word", "new_pwconfirm"]
+            }
+            RegistrationError::EmptyPassWordConfirm
+            | RegistrationError::InvalidPasswordConfirmation => vec!["new_pwconfirm"],
+            _ => vec![],
+        },
+        (
+            AccountSection::MailAddress,
+            ApiError::RegistrationError(
+                RegistrationError::EmptyMailAddress
+                | RegistrationError::MailAddressExistsAlready
+                | RegistrationError::InvalidMailAddressFormat,
+            ),
+        ) => vec!["new_mail_addr"],
+        (
+            AccountSection::UserName,
+            ApiError::RegistrationError(
+                RegistrationError::EmptyUserName
+                | RegistrationError::UserNameExistsAlready
+                | RegistrationError::InvalidUserNameFormat,
+            ),
+        ) => vec!["new_user_name"],
+        _ => vec![],
+    };
+    for id in ids {
+        mark_textfield_validity(document, id, false);
+    }
+}
@@ -1,8 +1,16 @@
-use stylist::css;
+use crate::services::{
+    head::{use_document_head, DocumentHead},
+    i18n::use_i18n,
+};
+use stylist::yew::styled_component;
 use yew::prelude::*;
 
-#[function_component]
+#[styled_component]
 pub fn HomeScreen() -> Html {
+    let i18n = use_i18n();
+    use_document_head(
+        DocumentHead::new(i18n.tr("nav-home")).description(i18n.tr("meta-home-description")),
+    );
     let counter = use_state(|| 0);
     let onclick = {
         let counter = counter.clone();
@@ -15,7 +23,7 @@ pub fn HomeScreen() -> Html {
     let class = css!(
         "
             p {
-                color: green;
+                color: var(--color-success);
             }
         "
     );
@@ -1,10 +0,0 @@
-use yew::prelude::*;
-
-#[function_component]
-pub fn ImprintScreen() -> Html {
-    html! {
-        <div>
-            <p>{ "Imprint" }</p>
-        </div>
-    }
-}
@@ -0,0 +1,172 @@
+use crate::services::{
+    dom,
+    head::{use_document_head, DocumentHead},
+    i18n::{self, use_i18n, Locale},
+    routing::{self, Route},
+};
+use fluent::fluent_args;
+use pulldown_cmark::{html::push_html, Event, Options, Parser, Tag};
+use stylist::yew::styled_component;
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlAnchorElement};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+// Bump this whenever `terms.md` changes, so that all users have to accept the new version.
+pub const TERMS_VERSION: &str = "2024-05-01";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LegalDocument {
+    Imprint,
+    PrivacyPolicy,
+    TermsOfService,
+}
+
+impl LegalDocument {
+    pub const ALL: [LegalDocument; 3] = [
+        LegalDocument::Imprint,
+        LegalDocument::PrivacyPolicy,
+        LegalDocument::TermsOfService,
+    ];
+
+    pub fn title(&self) -> String {
+        let key = match self {
+            LegalDocument::Imprint => "legal-imprint",
+            LegalDocument::PrivacyPolicy => "legal-privacy-policy",
+            LegalDocument::TermsOfService => "legal-terms-of-service",
+        };
+        i18n::tr(key)
+    }
+
+    pub fn route(&self, locale: Locale) -> Route {
+        match self {
+            LegalDocument::Imprint => Route::Imprint { locale },
+            LegalDocument::PrivacyPolicy => Route::Privacy { locale },
+            LegalDocument::TermsOfService => Route::Terms { locale },
+        }
+    }
+
+    fn markdown(&self) -> &'static str {
+        match self {
+            LegalDocument::Imprint => include_str!("../../content/legal/imprint.md"),
+            LegalDocument::PrivacyPolicy => include_str!("../../content/legal/privacy.md"),
+            LegalDocument::TermsOfService => include_str!("../../content/legal/terms.md"),
+        }
+    }
+
+    // The Markdown files are embedded at compile time, so their content is trusted. Links
+    // between the pages are written as route paths without the locale, such as `/imprint`.
+    pub fn to_html(&self, locale: Locale) -> Html {
+        let parser =
+            Parser::new_ext(self.markdown(), Options::ENABLE_TABLES).map(|event| match event {
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    title,
+                    id,
+                }) => {
+                    let dest_url = localized_href(&dest_url, locale)
+                        .map(Into::into)
+                        .unwrap_or(dest_url);
+                    Event::Start(Tag::Link {
+                        link_type,
+                        dest_url,
+                        title,
+                        id,
+                    })
+                }
+                event => event,
+            });
+        let mut html = String::new();
+        push_html(&mut html, parser);
+        Html::from_html_unchecked(html.into())
+    }
+}
+
+fn localized_href(dest_url: &str, locale: Locale) -> Option<String> {
+    if !dest_url.starts_with('/') {
+        return None;
+    }
+    let route = Route::recognize(&format!("/{}{}", locale, dest_url))?;
+    (route != Route::NotFound).then(|| routing::href(&route.to_path()))
+}
+
+// The route of a link to a page of the app, which the router can follow without reloading.
+fn internal_route(event: &MouseEvent) -> Option<Route> {
+    if event.button() != 0 || event.ctrl_key() || event.meta_key() || event.shift_key() {
+        return None;
+    }
+    let anchor = event
+        .target_dyn_into::<Element>()?
+        .closest("a")
+        .ok()??
+        .dyn_into::<HtmlAnchorElement>()
+        .ok()?;
+    if anchor.origin() != dom::window().location().origin().ok()? {
+        return None;
+    }
+    let path = routing::route_path_of(&anchor.pathname(), &anchor.hash());
+    Route::recognize(&path).filter(|route| *route != Route::NotFound)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LegalScreenProps {
+    pub document: LegalDocument,
+}
+
+#[styled_component]
+pub fn LegalScreen(props: &LegalScreenProps) -> Html {
+    let i18n = use_i18n();
+    let navigator = use_navigator();
+    let content = use_memo((props.document, i18n.locale), |(document, locale)| {
+        document.to_html(*locale)
+    });
+    use_document_head(
+        DocumentHead::new(props.document.title()).description(i18n.tr("meta-legal-description")),
+    );
+
+    let class = css!(
+        "
+            width: min(100% - 2em, 45em);
+            margin: auto;
+            line-height: var(--line-height);
+
+            nav {
+                display: flex;
+                gap: var(--space-m);
+                padding-top: var(--space-m);
+                padding-bottom: var(--space-m);
+                border-bottom: 1px solid var(--color-border);
+            }
+            nav a.active {
+                font-weight: bold;
+            }
+            p.version {
+                color: var(--color-muted);
+            }
+        "
+    );
+
+    let follow_link = move |event: MouseEvent| {
+        if let (Some(route), Some(navigator)) = (internal_route(&event), &navigator) {
+            event.prevent_default();
+            navigator.push(&route);
+        }
+    };
+
+    let links = LegalDocument::ALL.into_iter().map(|document| {
+        let classes = classes!((document == props.document).then_some("active"));
+        html! {
+            <Link<Route> { classes } to={ document.route(i18n.locale) }>{ document.title() }</Link<Route>>
+        }
+    });
+
+    html! {
+        <div { class }>
+            <nav>{ for links }</nav>
+            <article onclick={ follow_link }>{ (*content).clone() }</article>
+            if props.document == LegalDocument::TermsOfService {
+                <p class="version">{ i18n.tr_args("legal-version", &fluent_args!["version" => TERMS_VERSION]) }</p>
+            }
+        </div>
+    }
+}
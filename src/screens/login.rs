@@ -1,16 +1,26 @@
+use crate::screens::legal::TERMS_VERSION;
 use crate::services::{
-    dom::{self, text_field_is_empty},
+    api,
+    consent::{ConsentAction, ConsentCategory, ConsentContext},
+    dom::{
+        self, mark_textfield_validity, set_info_text_error, set_info_text_ok, text_field_is_empty,
+    },
     hashing::generate_hashed_password,
+    head::{use_document_head, DocumentHead},
+    i18n::{self, translate_api_message, use_i18n},
+    routing::{self, Route},
+    session::UserContext,
 };
+use fluent::fluent_args;
+use gloo::timers::callback::Timeout;
 use rs_web_api_models::api_message::{
     ApiError, ApiMessage, LoginError, PasswordResetError, RegistrationError,
 };
 use std::collections::HashMap;
-use stylist::css;
-use web_sys::{wasm_bindgen::JsCast, Document, Element, HtmlInputElement};
+use stylist::yew::styled_component;
+use web_sys::{wasm_bindgen::JsCast, Document, HtmlInputElement};
 use yew::prelude::*;
-
-const BACKEND_URL: &str = "http://localhost:8000/api";
+use yew_router::prelude::*;
 
 pub enum DisplayMode {
     Login,
@@ -18,17 +28,39 @@ pub enum DisplayMode {
     ForgotPassword,
 }
 
-#[function_component]
+#[styled_component]
 pub fn LoginScreen() -> Html {
+    let i18n = use_i18n();
     let display_mode = use_state(|| DisplayMode::Login);
+    let session = use_context::<UserContext>();
+    let consent = use_context::<ConsentContext>();
+    let title_key = match *display_mode {
+        DisplayMode::Login => "login-title",
+        DisplayMode::Registration => "registration-title",
+        DisplayMode::ForgotPassword => "password-reset-title",
+    };
+    use_document_head(
+        DocumentHead::new(i18n.tr(title_key)).description(i18n.tr("meta-login-description")),
+    );
+    let retry_after_secs = use_state(|| 0);
+
+    {
+        let retry_after_secs = retry_after_secs.clone();
+        use_effect_with(*retry_after_secs, move |secs| {
+            let secs = *secs;
+            let timeout =
+                (secs > 0).then(|| Timeout::new(1_000, move || retry_after_secs.set(secs - 1)));
+            move || drop(timeout)
+        });
+    }
 
     let class = css!(
         "
-            --pad: 0.5em;
-            --background-color: gray;
-            --border-color: var(--background-color);
+            --pad: var(--space-s);
+            --background-color: var(--color-primary);
+            --border-color: var(--color-border);
             --border: 1px solid var(--border-color);
-            --border-radius: 5px;
+            --border-radius: var(--radius-m);
 
             width: 100%;
             form {
@@ -54,6 +86,8 @@ pub fn LoginScreen() -> Html {
             }
 
             input[type=text], input[type=password] {
+                background-color: var(--color-surface);
+                color: var(--color-text);
                 width: calc(100% - 3*var(--pad));
                 padding-left: calc(1.5*var(--pad));
                 padding-right: calc(1.5*var(--pad));
@@ -61,11 +95,12 @@ pub fn LoginScreen() -> Html {
 
             input[type=button] {
                 cursor: pointer;
-                background-color: white;
+                background-color: var(--color-surface);
+                color: var(--color-text);
             }
             input[type=button].submit {
                 background-color: var(--background-color);
-                color: white;
+                color: var(--color-on-primary);
             }
 
             .buttons {
@@ -81,69 +116,116 @@ pub fn LoginScreen() -> Html {
             }
 
             input, label {
-                font-size: 1em;
+                font-size: var(--font-size-base);
                 margin-top: var(--pad);
                 margin-bottom: var(--pad);
                 width: calc(100%);
             }
 
-            #info_text {
+            label.checkbox {
+                display: block;
+            }
+            input[type=checkbox] {
+                width: auto;
+                margin-right: var(--pad);
+            }
+            button.consent {
+                padding: 0px;
+                border: none;
+                background: none;
+                font: inherit;
+                color: var(--color-link);
+                cursor: pointer;
+                text-decoration: underline;
+            }
+
+            input[type=button]:disabled {
+                cursor: not-allowed;
+                opacity: 0.5;
+            }
+
+            #info_text, p.notice, p.throttled {
                 text-align: center;
             }
             p.success {
-                color: green;
+                color: var(--color-success);
             }
             p.error {
-                color: red;
+                color: var(--color-error);
             }
             input[type=text].error, input[type=password].error {
-                outline: 2px solid red;
+                outline: 2px solid var(--color-error);
+            }
+            input[type=checkbox].error {
+                outline: 2px solid var(--color-error);
             }
         "
     );
 
-    let window = dom::window();
-    let document = dom::document(window);
+    let notice = session.and_then(|session| session.notice.clone());
+    let can_remember = consent.as_ref().is_some_and(|consent| {
+        let consent = consent.consent.as_ref();
+        consent.is_some_and(|consent| consent.is_granted(ConsentCategory::RememberMe))
+    });
+    let edit_consent = move |_| {
+        if let Some(consent) = &consent {
+            consent.dispatch(ConsentAction::Edit);
+        }
+    };
+    let is_throttled = *retry_after_secs > 0;
+    let throttle_text = match is_throttled {
+        true => html! {
+            <p class="error throttled">
+                { i18n.tr_args("login-throttled", &fluent_args!["secs" => *retry_after_secs]) }
+            </p>
+        },
+        false => html! {},
+    };
+
     let form = match *display_mode {
         DisplayMode::Login => {
             let forgot_password = {
                 let display_mode = display_mode.clone();
-                let document = document.clone();
                 move |_| {
-                    clear_info_text(&document);
+                    clear_info_text(&dom::document(dom::window()));
                     display_mode.set(DisplayMode::ForgotPassword);
                 }
             };
             let switch_to_registration = {
                 let display_mode = display_mode.clone();
-                let document = document.clone();
                 move |_| {
-                    clear_info_text(&document);
+                    clear_info_text(&dom::document(dom::window()));
                     display_mode.set(DisplayMode::Registration);
                 }
             };
             let submit = {
+                let retry_after_secs = retry_after_secs.clone();
                 move |_| {
                     let window = dom::window();
                     let document = dom::document(window);
 
                     let user_info = dom::read_value_from_text_field(&document, "user_info");
                     let pass_word = dom::read_value_from_text_field(&document, "pass_word");
+                    let remember_me = dom::read_checkbox(&document, "remember_me");
 
                     clear_info_text(&document);
                     let is_valid = assure_validity_of_fields(DisplayMode::Login, &document);
 
                     if is_valid {
+                        let retry_after_secs = retry_after_secs.clone();
                         wasm_bindgen_futures::spawn_local(async move {
-                            let client = reqwest::Client::new();
-                            let url = format!("{}/login", BACKEND_URL);
-
                             let pass_hash = generate_hashed_password(&pass_word);
-                            let body = HashMap::from([
-                                ("user_info", &user_info),
-                                ("pass_hash", &pass_hash), //
-                            ]);
-                            let result = client.post(url).json(&body).send().await.unwrap();
+                            let body = serde_json::json!({
+                                "user_info": &user_info,
+                                "pass_hash": &pass_hash,
+                                "remember_me": remember_me,
+                            });
+                            let result = api::post("/login", &body).await.unwrap();
+                            if let Some(secs) = api::retry_after(&result) {
+                                clear_info_text(&document);
+                                retry_after_secs.set(secs);
+                                return;
+                            }
 
                             // let headers = result.headers();
                             // let cookies = result.cookies();
@@ -151,10 +233,13 @@ pub fn LoginScreen() -> Html {
 
                             let info_text = document.get_element_by_id("info_text").unwrap();
                             let api_msg: ApiMessage = result.json().await.unwrap();
-                            let api_msg_str: String = api_msg.clone().into();
+                            let api_msg_str = translate_api_message(&api_msg);
                             match &api_msg {
-                                ApiMessage::Ok(msg) => {
+                                ApiMessage::Ok(_msg) => {
                                     set_info_text_ok(&info_text, &api_msg_str);
+                                    if !api::start_session(&user_info, remember_me).await {
+                                        gloo::console::log!("failed to obtain an access token");
+                                    }
                                 }
                                 ApiMessage::Err(msg) => {
                                     set_info_text_error(&info_text, &api_msg_str);
@@ -166,10 +251,10 @@ pub fn LoginScreen() -> Html {
                                                 f(&document, "pass_word", false);
                                             }
                                             LoginError::EmptyUserInfo => {
-                                                f(&document, "pass_word", false);
+                                                f(&document, "user_info", false);
                                             }
                                             LoginError::EmptyPassWord => {
-                                                f(&document, "user_info", false);
+                                                f(&document, "pass_word", false);
                                             }
                                         }
                                     }
@@ -184,14 +269,34 @@ pub fn LoginScreen() -> Html {
 
             html! {
                 <form>
-                    <p class="title">{ "Login" }</p>
-                    <input type="text" name="user_info" id="user_info" placeholder="Username or E-Mail" /> <br/>
-                    <input type="password" name="pass_word" id="pass_word" placeholder="Password" /> <br/>
+                    <p class="title">{ i18n.tr("login-title") }</p>
+                    <input type="text" name="user_info" id="user_info"
+                        placeholder={ i18n.tr("login-user-info") } /> <br/>
+                    <input type="password" name="pass_word" id="pass_word"
+                        placeholder={ i18n.tr("login-pass-word") } /> <br/>
+                    <label class="checkbox">
+                        <input type="checkbox" name="remember_me" id="remember_me"
+                            disabled={ !can_remember } />
+                        { i18n.tr("login-remember-me") }
+                        if !can_remember {
+                            { " " }
+                            <button type="button" class="consent" onclick={ edit_consent }>
+                                { i18n.tr("login-requires-consent") }
+                            </button>
+                        }
+                    </label>
                     <div class="buttons">
-                        <input type="button" class="left" value="Forgot Password?" onclick={ forgot_password } />
-                        <input type="button" class="right submit" value="Submit" onclick={ submit } />
+                        <input type="button" class="left" value={ i18n.tr("login-forgot-password") }
+                            onclick={ forgot_password } />
+                        <input type="button" class="right submit" value={ i18n.tr("submit") }
+                            disabled={ is_throttled } onclick={ submit } />
                     </div>
-                    <input type="button" value="Register new Account" onclick={ switch_to_registration } />
+                    <input type="button" value={ i18n.tr("login-register") }
+                        onclick={ switch_to_registration } />
+                    if let Some(notice) = notice {
+                        <p class="notice">{ i18n.tr(&notice) }</p>
+                    }
+                    { throttle_text.clone() }
                     <p id="info_text"></p>
                 </form>
             }
@@ -199,14 +304,14 @@ pub fn LoginScreen() -> Html {
         DisplayMode::Registration => {
             let switch_to_login = {
                 let display_mode = display_mode.clone();
-                let document = document.clone();
                 move |_| {
-                    clear_info_text(&document);
+                    clear_info_text(&dom::document(dom::window()));
                     display_mode.set(DisplayMode::Login);
                 }
             };
 
             let submit = {
+                let retry_after_secs = retry_after_secs.clone();
                 move |_| {
                     let window = dom::window();
                     let document = dom::document(window);
@@ -214,25 +319,29 @@ pub fn LoginScreen() -> Html {
                     let user_name = dom::read_value_from_text_field(&document, "user_name");
                     let mail_addr = dom::read_value_from_text_field(&document, "mail_addr");
                     let pass_word = dom::read_value_from_text_field(&document, "pass_word");
-                    let pwconfirm = dom::read_value_from_text_field(&document, "pwconfirm");
 
                     clear_info_text(&document);
                     let is_valid = assure_validity_of_fields(DisplayMode::Registration, &document);
                     // TODO Send registration form data to server.
 
                     if is_valid {
+                        let retry_after_secs = retry_after_secs.clone();
                         wasm_bindgen_futures::spawn_local(async move {
-                            let client = reqwest::Client::new();
-                            let url = format!("{}/register", BACKEND_URL);
-
                             let pass_hash = generate_hashed_password(&pass_word);
                             println!("{}", pass_hash);
+                            let terms_version = TERMS_VERSION.to_string();
                             let body = HashMap::from([
                                 ("user_name", &user_name),
                                 ("mail_addr", &mail_addr),
                                 ("pass_hash", &pass_hash),
+                                ("terms_version", &terms_version),
                             ]);
-                            let result = client.post(url).json(&body).send().await.unwrap();
+                            let result = api::post("/register", &body).await.unwrap();
+                            if let Some(secs) = api::retry_after(&result) {
+                                clear_info_text(&document);
+                                retry_after_secs.set(secs);
+                                return;
+                            }
 
                             // let headers = result.headers();
                             // let cookies = result.cookies();
@@ -240,9 +349,9 @@ pub fn LoginScreen() -> Html {
 
                             let info_text = document.get_element_by_id("info_text").unwrap();
                             let api_msg: ApiMessage = result.json().await.unwrap();
-                            let api_msg_str: String = api_msg.clone().into();
+                            let api_msg_str = translate_api_message(&api_msg);
                             match &api_msg {
-                                ApiMessage::Ok(msg) => {
+                                ApiMessage::Ok(_msg) => {
                                     set_info_text_ok(&info_text, &api_msg_str);
                                 }
                                 ApiMessage::Err(msg) => {
@@ -282,16 +391,28 @@ pub fn LoginScreen() -> Html {
 
             html! {
                 <form>
-                    <p class="title">{ "Registration" }</p>
-                    <input type="text" name="user_name" id="user_name" placeholder="Username" /> <br/>
-                    <input type="text" name="mail_addr" id="mail_addr" placeholder="E-Mail" /> <br/>
-                    <input type="password" name="pass_word" id="pass_word" placeholder="Password" /> <br/>
-                    <input type="password" name="pwconfirm"
-                        id="pwconfirm" placeholder="Confirm Password" /> <br/>
+                    <p class="title">{ i18n.tr("registration-title") }</p>
+                    <input type="text" name="user_name" id="user_name"
+                        placeholder={ i18n.tr("registration-user-name") } /> <br/>
+                    <input type="text" name="mail_addr" id="mail_addr"
+                        placeholder={ i18n.tr("registration-mail-addr") } /> <br/>
+                    <input type="password" name="pass_word" id="pass_word"
+                        placeholder={ i18n.tr("login-pass-word") } /> <br/>
+                    <input type="password" name="pwconfirm" id="pwconfirm"
+                        placeholder={ i18n.tr("registration-pwconfirm") } /> <br/>
+                    <label class="checkbox">
+                        <input type="checkbox" name="accept_terms" id="accept_terms" />
+                        { i18n.tr("registration-accept-terms") }
+                        { " " }
+                        <a href={ routing::href(&Route::Terms { locale: i18n.locale }.to_path()) } target="_blank">{ i18n.tr("legal-terms-of-service") }</a>
+                    </label>
                     <div class="buttons">
-                        <input type="button" class="left" value="Back to Login" onclick={ switch_to_login } />
-                        <input type="button" class="right submit" value="Submit" onclick={ submit } />
+                        <input type="button" class="left" value={ i18n.tr("login-back-to-login") }
+                            onclick={ switch_to_login } />
+                        <input type="button" class="right submit" value={ i18n.tr("submit") }
+                            disabled={ is_throttled } onclick={ submit } />
                     </div>
+                    { throttle_text.clone() }
                     <p id="info_text"></p>
                 </form>
             }
@@ -299,13 +420,13 @@ pub fn LoginScreen() -> Html {
         DisplayMode::ForgotPassword => {
             let switch_to_login = {
                 let display_mode = display_mode.clone();
-                let document = document.clone();
                 move |_| {
-                    clear_info_text(&document);
+                    clear_info_text(&dom::document(dom::window()));
                     display_mode.set(DisplayMode::Login);
                 }
             };
             let submit = {
+                let retry_after_secs = retry_after_secs.clone();
                 move |_| {
                     let window = dom::window();
                     let document = dom::document(window);
@@ -317,11 +438,15 @@ pub fn LoginScreen() -> Html {
                         assure_validity_of_fields(DisplayMode::ForgotPassword, &document);
 
                     if is_valid {
+                        let retry_after_secs = retry_after_secs.clone();
                         wasm_bindgen_futures::spawn_local(async move {
-                            let client = reqwest::Client::new();
-                            let url = format!("{}/reset_password", BACKEND_URL);
                             let body = HashMap::from([("user_info", &user_info)]);
-                            let result = client.post(url).json(&body).send().await.unwrap();
+                            let result = api::post("/reset_password", &body).await.unwrap();
+                            if let Some(secs) = api::retry_after(&result) {
+                                clear_info_text(&document);
+                                retry_after_secs.set(secs);
+                                return;
+                            }
 
                             // let headers = result.headers();
                             // let cookies = result.cookies();
@@ -329,7 +454,7 @@ pub fn LoginScreen() -> Html {
 
                             let info_text = document.get_element_by_id("info_text").unwrap();
                             let api_msg: ApiMessage = result.json().await.unwrap();
-                            let api_msg_str: String = api_msg.clone().into();
+                            let api_msg_str = translate_api_message(&api_msg);
                             match &api_msg {
                                 ApiMessage::Ok(_msg) => {
                                     set_info_text_ok(&info_text, &api_msg_str);
@@ -353,12 +478,16 @@ pub fn LoginScreen() -> Html {
 
             html! {
                 <form>
-                    <p class="title">{ "Password Reset" }</p>
-                    <input type="text" name="user_info" id="user_info" placeholder="Username or E-Mail" /> <br/>
+                    <p class="title">{ i18n.tr("password-reset-title") }</p>
+                    <input type="text" name="user_info" id="user_info"
+                        placeholder={ i18n.tr("login-user-info") } /> <br/>
                     <div class="buttons">
-                        <input type="button" class="left" value="Back to Login" onclick={ switch_to_login } />
-                        <input type="button" class="right submit" value="Submit" onclick={ submit } />
+                        <input type="button" class="left" value={ i18n.tr("login-back-to-login") }
+                            onclick={ switch_to_login } />
+                        <input type="button" class="right submit" value={ i18n.tr("submit") }
+                            disabled={ is_throttled } onclick={ submit } />
                     </div>
+                    { throttle_text.clone() }
                     <p id="info_text"></p>
                 </form>
             }
@@ -397,13 +526,13 @@ fn assure_validity_of_fields(display_mode: DisplayMode, document: &Document) ->
 
             if text_field_is_empty(&user_info_text_field) {
                 let msg = LoginError::EmptyUserInfo;
-                let msg: String = ApiMessage::Err(ApiError::LoginError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::LoginError(msg)));
                 mark_textfield_validity(document, "user_info", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if text_field_is_empty(&pass_word_text_field) {
                 let msg = LoginError::EmptyPassWord;
-                let msg: String = ApiMessage::Err(ApiError::LoginError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::LoginError(msg)));
                 mark_textfield_validity(document, "pass_word", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
@@ -412,7 +541,7 @@ fn assure_validity_of_fields(display_mode: DisplayMode, document: &Document) ->
                 || !rs_web_api_models::validation::is_valid_pass_word(&pass_word)
             {
                 let msg = LoginError::InvalidLoginCredentials;
-                let msg: String = ApiMessage::Err(ApiError::LoginError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::LoginError(msg)));
                 mark_textfield_validity(document, "user_info", false);
                 mark_textfield_validity(document, "pass_word", false);
                 set_info_text_error(&info_text, &msg);
@@ -424,6 +553,7 @@ fn assure_validity_of_fields(display_mode: DisplayMode, document: &Document) ->
             mark_textfield_validity(document, "mail_addr", true);
             mark_textfield_validity(document, "pass_word", true);
             mark_textfield_validity(document, "pwconfirm", true);
+            mark_textfield_validity(document, "accept_terms", true);
 
             let user_name_text_field = document.get_element_by_id("user_name").unwrap();
             let mail_addr_text_field = document.get_element_by_id("mail_addr").unwrap();
@@ -437,52 +567,56 @@ fn assure_validity_of_fields(display_mode: DisplayMode, document: &Document) ->
 
             if text_field_is_empty(&user_name_text_field) {
                 let msg = RegistrationError::EmptyUserName;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "user_name", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if !user_name_is_valid {
                 let msg = RegistrationError::InvalidUserNameFormat;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "user_name", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if text_field_is_empty(&mail_addr_text_field) {
                 let msg = RegistrationError::EmptyMailAddress;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "mail_addr", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if !mail_addr_is_valid {
                 let msg = RegistrationError::InvalidMailAddressFormat;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "mail_addr", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if text_field_is_empty(&pass_word_text_field) {
                 let msg = RegistrationError::EmptyPassWord;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "pass_word", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if !pass_word_is_valid {
                 let msg = RegistrationError::InvalidPasswordFormat;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "pass_word", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if text_field_is_empty(&pwconfirm_text_field) {
                 let msg = RegistrationError::EmptyPassWordConfirm;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "pwconfirm", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if !pwconfirm_is_valid {
                 let msg = RegistrationError::InvalidPasswordConfirmation;
-                let msg: String = ApiMessage::Err(ApiError::RegistrationError(msg)).into();
+                let msg = translate_api_message(&ApiMessage::Err(ApiError::RegistrationError(msg)));
                 mark_textfield_validity(document, "pwconfirm", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
+            } else if !dom::read_checkbox(document, "accept_terms") {
+                mark_textfield_validity(document, "accept_terms", false);
+                set_info_text_error(&info_text, &i18n::tr("terms-not-accepted"));
+                is_valid = false;
             }
         }
         DisplayMode::ForgotPassword => {
@@ -492,13 +626,15 @@ fn assure_validity_of_fields(display_mode: DisplayMode, document: &Document) ->
             let user_info_text_field = document.get_element_by_id("user_info").unwrap();
             if text_field_is_empty(&user_info_text_field) {
                 let msg = PasswordResetError::EmptyUserInfo;
-                let msg: String = ApiMessage::Err(ApiError::PasswordResetError(msg)).into();
+                let msg =
+                    translate_api_message(&ApiMessage::Err(ApiError::PasswordResetError(msg)));
                 mark_textfield_validity(document, "user_info", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
             } else if !user_info_is_valid {
                 let msg = PasswordResetError::AccountDoesNotExist;
-                let msg: String = ApiMessage::Err(ApiError::PasswordResetError(msg)).into();
+                let msg =
+                    translate_api_message(&ApiMessage::Err(ApiError::PasswordResetError(msg)));
                 mark_textfield_validity(document, "user_info", false);
                 set_info_text_error(&info_text, &msg);
                 is_valid = false;
@@ -564,20 +700,3 @@ fn clear_info_text(document: &Document) {
     let info_text = document.get_element_by_id("info_text").unwrap();
     info_text.set_inner_html("");
 }
-fn set_info_text_ok(info_text: &Element, text: &str) {
-    info_text.class_list().remove_1("error").unwrap();
-    info_text.set_class_name("success");
-    info_text.set_inner_html(text);
-}
-fn set_info_text_error(info_text: &Element, text: &str) {
-    info_text.class_list().remove_1("success").unwrap();
-    info_text.set_class_name("error");
-    info_text.set_inner_html(text);
-}
-fn mark_textfield_validity(document: &Document, element_id: &str, is_valid: bool) {
-    let text_field = document.get_element_by_id(element_id).unwrap();
-    match is_valid {
-        true => text_field.class_list().remove_1("error").unwrap(),
-        false => text_field.set_class_name("error"),
-    };
-}
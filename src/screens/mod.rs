@@ -1,4 +1,5 @@
+pub mod account;
 pub mod home;
-pub mod imprint;
+pub mod legal;
 pub mod login;
 pub mod page_not_found;
@@ -1,21 +1,145 @@
-use stylist::css;
+use crate::services::{
+    api,
+    consent::{self, ConsentCategory},
+    dom,
+    head::{use_document_head, DocumentHead},
+    i18n::{use_i18n, Locale},
+    routing::{self, Route},
+};
+use fluent::fluent_args;
+use stylist::yew::styled_component;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-#[function_component]
+const BROKEN_LINK_ENDPOINT: &str = "/reports/broken_link";
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// The known route closest to the path, as long as it looks like a typo rather than a
+// different page. Routes of the current locale win ties.
+fn closest_route(path: &str, locale: Locale) -> Option<Route> {
+    let path = path.trim_end_matches('/').to_lowercase();
+    let max_distance = (path.len() / 3).max(2);
+    let locales = std::iter::once(locale).chain(Locale::ALL);
+    locales
+        .flat_map(Route::localized)
+        .map(|route| (edit_distance(&path, &route.to_path()), route))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, route)| route)
+}
+
+// Links from other sites that lead nowhere are reported, as long as analytics are allowed.
+fn report_broken_link(path: String) {
+    let window = dom::window();
+    let origin = window.location().origin().unwrap_or_default();
+    let referrer = dom::document(window).referrer();
+    let is_inbound = !referrer.is_empty() && !referrer.starts_with(&origin);
+    if !is_inbound || !consent::is_granted(ConsentCategory::Analytics) {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        let body = serde_json::json!({
+            "path": path,
+            "referrer": referrer,
+        });
+        if let Err(err) = api::post(BROKEN_LINK_ENDPOINT, &body).await {
+            gloo::console::log!(err.to_string());
+        }
+    });
+}
+
+#[styled_component]
 pub fn PageNotFoundScreen() -> Html {
+    let i18n = use_i18n();
+    let navigator = use_navigator();
+    let location = use_location();
+    use_document_head(DocumentHead::new(i18n.tr("nav-not-found")));
+
+    let path = location
+        .map(|location| routing::route_path(location.path()))
+        .unwrap_or_default();
+    // Found once mounted, so that the markup rendered ahead of the client does not depend
+    // on the path it is eventually served for.
+    let suggestion = use_state_eq(|| None::<Route>);
+    {
+        let suggestion = suggestion.clone();
+        let locale = i18n.locale;
+        use_effect_with(path.clone(), move |path| {
+            suggestion.set(closest_route(path, locale));
+            report_broken_link(path.clone());
+        });
+    }
+
     let class = css!(
         "
+            text-align: center;
+            padding: var(--space-m);
+
             h1 {
                 width: 100%;
-                text-align: center;
-                color: red;
+                color: var(--color-error);
+            }
+            code {
+                word-break: break-all;
+            }
+            .links {
+                display: flex;
+                justify-content: center;
+                gap: var(--space-m);
+                margin-top: var(--space-l);
+            }
+            input[type=button] {
+                font-size: var(--font-size-base);
+                cursor: pointer;
+                border: none;
+                border-radius: var(--radius-m);
+                outline: 1px solid var(--color-border);
+                background-color: var(--color-surface);
+                color: var(--color-text);
             }
         "
     );
 
+    let suggestion = (*suggestion).clone().map(|route| {
+        let path = route.to_path();
+        html! {
+            <p>
+                { i18n.tr("not-found-suggestion") }
+                { " " }
+                <Link<Route> to={ route }>{ path }</Link<Route>>
+            </p>
+        }
+    });
+    let go_back = move |_| {
+        if let Some(navigator) = &navigator {
+            navigator.back();
+        }
+    };
+
     html! {
         <div {class}>
             <h1>{ "404" }</h1>
+            <p>{ i18n.tr_args("not-found-path", &fluent_args!["path" => path]) }</p>
+            { suggestion }
+            <div class="links">
+                <Link<Route> to={ Route::Home { locale: i18n.locale } }>
+                    { i18n.tr("not-found-home") }
+                </Link<Route>>
+                <input type="button" value={ i18n.tr("not-found-back") } onclick={ go_back } />
+            </div>
         </div>
     }
 }
@@ -0,0 +1,107 @@
+use crate::app::{ServerApp, ServerAppProps};
+use crate::services::{
+    head::{self, HeadCollector},
+    i18n::{self, Locale},
+    routing::{self, Route},
+};
+use std::collections::HashMap;
+use stylist::manager::{render_static, StyleManager};
+use yew::ServerRenderer;
+use yew_router::Routable;
+
+// The page built by trunk, split at the places where the rendered markup is inserted.
+pub struct Template {
+    head: String,
+    body_start: String,
+    body_end: String,
+}
+
+impl Template {
+    pub fn parse(index_html: &str) -> Self {
+        let (head, rest) = index_html
+            .split_once("</head>")
+            .expect("no `</head>` in index.html");
+        let body_open = rest
+            .find("<body")
+            .and_then(|start| rest[start..].find('>').map(|end| start + end + 1))
+            .expect("no `<body>` in index.html");
+        // The static title is replaced by the one of the rendered screen.
+        let head = match (head.find("<title>"), head.find("</title>")) {
+            (Some(start), Some(end)) => {
+                format!("{}{}", &head[..start], &head[end + "</title>".len()..])
+            }
+            _ => head.to_string(),
+        };
+        Self {
+            head,
+            body_start: format!("</head>{}", &rest[..body_open]),
+            body_end: rest[body_open..].to_string(),
+        }
+    }
+
+    fn render(&self, locale: Locale, head: &str, body: &str) -> String {
+        let lang = format!("lang=\"{}\"", locale);
+        let html_head = self.head.replacen("lang=\"en\"", &lang, 1);
+        format!(
+            "{}{}{}{}{}",
+            html_head, head, self.body_start, body, self.body_end
+        )
+    }
+}
+
+// Renders the page for a path starting with a locale, along with the route it belongs to.
+pub async fn render_page(
+    template: &Template,
+    origin: &str,
+    path: &str,
+    queries: HashMap<String, String>,
+) -> (Route, String) {
+    let locale = i18n::path_locale(path).unwrap_or_default();
+    let route = Route::recognize(path).unwrap_or(Route::NotFound);
+    let (writer, reader) = render_static();
+    let head = HeadCollector::new(origin);
+    let props = {
+        let url = path.to_string();
+        let head = head.clone();
+        move || ServerAppProps {
+            url: url.into(),
+            queries,
+            manager: StyleManager::builder()
+                .writer(writer)
+                .build()
+                .expect("failed to create style manager"),
+            head,
+        }
+    };
+    let body = ServerRenderer::<ServerApp>::with_props(props)
+        .render()
+        .await;
+
+    let mut head_markup = head.markup().unwrap_or_else(|| {
+        let title = i18n::translate(locale, "app-name", None);
+        format!("<title>{}</title>", title)
+    });
+    // Lets the client tell whether this markup belongs to the path it was served for.
+    head_markup.push_str(&format!(
+        "<meta name=\"{}\" content=\"{}\">",
+        routing::RENDERED_PATH_META_NAME,
+        head::escape(path)
+    ));
+    if route != Route::NotFound {
+        for (hreflang, path) in routing::alternate_paths(&route) {
+            head_markup.push_str(&format!(
+                "<link rel=\"alternate\" hreflang=\"{}\" href=\"{}{}\">",
+                hreflang, origin, path
+            ));
+        }
+    }
+    if let Err(err) = reader
+        .read_style_data()
+        .write_static_markup(&mut head_markup)
+    {
+        eprintln!("failed to write styles: {}", err);
+    }
+
+    let page = template.render(locale, &head_markup, &body);
+    (route, page)
+}
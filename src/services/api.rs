@@ -0,0 +1,171 @@
+use crate::services::session::{self, SessionAction};
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use reqwest::{header::RETRY_AFTER, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+pub const BACKEND_URL: &str = "http://localhost:8000/api";
+
+const SESSION_EXPIRED_NOTICE: &str = "notice-session-expired";
+const DEFAULT_RETRY_AFTER_SECS: u32 = 60;
+
+#[derive(Deserialize)]
+struct AccessToken {
+    access_token: String,
+}
+
+type PendingRefresh = Shared<LocalBoxFuture<'static, Option<String>>>;
+
+thread_local! {
+    static PENDING_REFRESH: RefCell<Option<PendingRefresh>> = RefCell::new(None);
+}
+
+pub async fn get(endpoint: &str) -> reqwest::Result<Response> {
+    let url = format!("{}{}", BACKEND_URL, endpoint);
+    send(|| reqwest::Client::new().get(&url)).await
+}
+
+pub async fn delete(endpoint: &str) -> reqwest::Result<Response> {
+    let url = format!("{}{}", BACKEND_URL, endpoint);
+    send(|| reqwest::Client::new().delete(&url)).await
+}
+
+pub async fn post<T: Serialize + ?Sized>(endpoint: &str, body: &T) -> reqwest::Result<Response> {
+    let url = format!("{}{}", BACKEND_URL, endpoint);
+    send(|| reqwest::Client::new().post(&url).json(body)).await
+}
+
+async fn send(request: impl Fn() -> RequestBuilder) -> reqwest::Result<Response> {
+    // Requests issued while the access token is being refreshed wait for the new token.
+    if let Some(pending_refresh) = pending_refresh() {
+        pending_refresh.await;
+    }
+
+    let response = authorize(request()).send().await?;
+    if response.status() != StatusCode::UNAUTHORIZED || session::access_token().is_none() {
+        return Ok(response);
+    }
+
+    match extend_session().await {
+        true => authorize(request()).send().await,
+        false => Ok(response),
+    }
+}
+
+// Returns the number of seconds to wait if the backend throttled the request or locked
+// the account. Note that the backend has to expose the `Retry-After` header via CORS.
+pub fn retry_after(response: &Response) -> Option<u32> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::LOCKED
+    ) {
+        return None;
+    }
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after);
+    Some(retry_after.unwrap_or(DEFAULT_RETRY_AFTER_SECS))
+}
+
+// The header contains either a number of seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<u32> {
+    if let Ok(secs) = value.trim().parse() {
+        return Some(secs);
+    }
+    let date = js_sys::Date::parse(value);
+    if date.is_nan() {
+        return None;
+    }
+    let secs = (date - js_sys::Date::now()) / 1000.;
+    Some(secs.ceil().max(1.) as u32)
+}
+
+fn authorize(request: RequestBuilder) -> RequestBuilder {
+    let request = with_credentials(request);
+    match session::access_token() {
+        Some(access_token) => request.bearer_auth(access_token),
+        None => request,
+    }
+}
+
+fn with_credentials(request: RequestBuilder) -> RequestBuilder {
+    // The refresh token is stored in an HTTP-only cookie set by the backend.
+    #[cfg(target_arch = "wasm32")]
+    let request = request.fetch_credentials_include();
+    request
+}
+
+fn pending_refresh() -> Option<PendingRefresh> {
+    PENDING_REFRESH.with(|pending| pending.borrow().clone())
+}
+
+pub async fn refresh_access_token() -> Option<String> {
+    let refresh = match pending_refresh() {
+        Some(pending_refresh) => pending_refresh,
+        None => {
+            let refresh = async {
+                let access_token = request_access_token().await;
+                PENDING_REFRESH.with(|pending| *pending.borrow_mut() = None);
+                if let Some(access_token) = &access_token {
+                    let access_token = access_token.clone();
+                    session::dispatch(SessionAction::Refresh { access_token });
+                }
+                access_token
+            }
+            .boxed_local()
+            .shared();
+            PENDING_REFRESH.with(|pending| *pending.borrow_mut() = Some(refresh.clone()));
+            refresh
+        }
+    };
+    refresh.await
+}
+
+async fn request_access_token() -> Option<String> {
+    let url = format!("{}/refresh", BACKEND_URL);
+    let request = with_credentials(reqwest::Client::new().post(url));
+    let response = request.send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let AccessToken { access_token } = response.json().await.ok()?;
+    Some(access_token)
+}
+
+pub async fn extend_session() -> bool {
+    let is_extended = refresh_access_token().await.is_some();
+    if !is_extended {
+        log_out(Some(SESSION_EXPIRED_NOTICE)).await;
+    }
+    is_extended
+}
+
+// Goes through the shared refresh, as a tab restoring its session may at the same time be
+// told about it by every other open tab.
+pub async fn start_session(user_info: &str, remember_me: bool) -> bool {
+    match refresh_access_token().await {
+        Some(access_token) => {
+            let user_info = user_info.to_string();
+            session::dispatch(SessionAction::LogIn {
+                user_info,
+                access_token,
+                remember_me,
+            });
+            true
+        }
+        None => false,
+    }
+}
+
+pub async fn log_out(notice: Option<&str>) {
+    let url = format!("{}/logout", BACKEND_URL);
+    let request = authorize(reqwest::Client::new().post(url));
+    if let Err(err) = request.send().await {
+        gloo::console::log!(err.to_string());
+    }
+
+    let notice = notice.map(String::from);
+    session::dispatch(SessionAction::LogOut { notice });
+}
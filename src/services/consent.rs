@@ -0,0 +1,154 @@
+use crate::services::{dom, i18n::LOCALE_KEY, session::SESSION_MARKER_KEY, theme::THEME_KEY};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use yew::prelude::*;
+
+// Bump this whenever the privacy policy changes, so that all visitors are asked again.
+pub const CONSENT_POLICY_VERSION: u32 = 1;
+const CONSENT_KEY: &str = "yew-client-consent";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsentCategory {
+    RememberMe,
+    Preferences,
+    Analytics,
+}
+
+impl ConsentCategory {
+    // Non-essential keys in the local storage, which are removed once consent is withdrawn.
+    fn storage_keys(&self) -> &'static [&'static str] {
+        match self {
+            ConsentCategory::RememberMe => &[SESSION_MARKER_KEY],
+            ConsentCategory::Preferences => &[LOCALE_KEY, THEME_KEY],
+            ConsentCategory::Analytics => &[],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Consent {
+    pub policy_version: u32,
+    pub remember_me: bool,
+    pub preferences: bool,
+    pub analytics: bool,
+}
+
+impl Consent {
+    pub fn new(remember_me: bool, preferences: bool, analytics: bool) -> Self {
+        Self {
+            policy_version: CONSENT_POLICY_VERSION,
+            remember_me,
+            preferences,
+            analytics,
+        }
+    }
+
+    pub fn is_granted(&self, category: ConsentCategory) -> bool {
+        match category {
+            ConsentCategory::RememberMe => self.remember_me,
+            ConsentCategory::Preferences => self.preferences,
+            ConsentCategory::Analytics => self.analytics,
+        }
+    }
+}
+
+// Consent given for an outdated policy version is treated as if none was given.
+pub fn stored_consent() -> Option<Consent> {
+    if !dom::is_browser() {
+        return None;
+    }
+    let consent: Consent = LocalStorage::get(CONSENT_KEY).ok()?;
+    (consent.policy_version == CONSENT_POLICY_VERSION).then_some(consent)
+}
+
+pub fn is_granted(category: ConsentCategory) -> bool {
+    stored_consent().is_some_and(|consent| consent.is_granted(category))
+}
+
+fn store_consent(consent: &Consent) {
+    if let Err(err) = LocalStorage::set(CONSENT_KEY, consent) {
+        gloo::console::log!(err.to_string());
+    }
+    let categories = [
+        ConsentCategory::RememberMe,
+        ConsentCategory::Preferences,
+        ConsentCategory::Analytics,
+    ];
+    for category in categories {
+        if !consent.is_granted(category) {
+            for key in category.storage_keys() {
+                LocalStorage::delete(key);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConsentState {
+    pub consent: Option<Consent>,
+    pub is_editing: bool,
+}
+
+pub enum ConsentAction {
+    Restore,
+    Save(Consent),
+    Edit,
+    Close,
+}
+
+impl Reducible for ConsentState {
+    type Action = ConsentAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let state = match action {
+            ConsentAction::Restore => ConsentState {
+                consent: stored_consent(),
+                ..(*self).clone()
+            },
+            ConsentAction::Save(consent) => ConsentState {
+                consent: Some(consent),
+                is_editing: false,
+            },
+            ConsentAction::Edit => ConsentState {
+                is_editing: true,
+                ..(*self).clone()
+            },
+            ConsentAction::Close => ConsentState {
+                is_editing: false,
+                ..(*self).clone()
+            },
+        };
+        state.into()
+    }
+}
+
+pub type ConsentContext = UseReducerHandle<ConsentState>;
+
+#[derive(Properties, PartialEq)]
+pub struct ConsentProviderProps {
+    pub children: Html,
+}
+
+#[function_component]
+pub fn ConsentProvider(props: &ConsentProviderProps) -> Html {
+    let consent = use_reducer(ConsentState::default);
+
+    // The stored consent is only read once mounted, so that hydration starts from the same
+    // state as the server.
+    {
+        let consent = consent.clone();
+        use_effect_with((), move |_| consent.dispatch(ConsentAction::Restore));
+    }
+    use_effect_with(consent.consent.clone(), |consent| {
+        if let Some(consent) = consent {
+            store_consent(consent);
+        }
+    });
+
+    html! {
+        <ContextProvider<ConsentContext> context={ consent }>
+            { props.children.clone() }
+        </ContextProvider<ConsentContext>>
+    }
+}
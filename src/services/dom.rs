@@ -1,4 +1,25 @@
-use web_sys::{wasm_bindgen::JsCast, Document, Element, HtmlElement, HtmlInputElement, Window};
+use web_sys::{
+    wasm_bindgen::JsCast, Document, Element, HtmlAnchorElement, HtmlElement, HtmlInputElement,
+    Window,
+};
+use yew::prelude::*;
+
+// Browser APIs are not available while rendering on the server.
+pub fn is_browser() -> bool {
+    cfg!(target_arch = "wasm32") && web_sys::window().is_some()
+}
+
+// False on the server and during hydration, so that both render the same markup before
+// anything that depends on the browser's storage is shown.
+#[hook]
+pub fn use_is_hydrated() -> bool {
+    let is_hydrated = use_state_eq(|| false);
+    {
+        let is_hydrated = is_hydrated.clone();
+        use_effect_with((), move |_| is_hydrated.set(true));
+    }
+    *is_hydrated
+}
 
 pub fn window() -> Window {
     web_sys::window().expect("no global `window` exists")
@@ -22,6 +43,25 @@ pub fn read_value_from_text_field(document: &Document, element_id: &str) -> Stri
         .value()
 }
 
+pub fn clear_text_field(document: &Document, element_id: &str) {
+    document
+        .get_element_by_id(element_id)
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap()
+        .set_value("");
+}
+
+pub fn read_checkbox(document: &Document, element_id: &str) -> bool {
+    document
+        .get_element_by_id(element_id)
+        .unwrap()
+        .clone()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap()
+        .checked()
+}
+
 pub fn text_field_is_empty(text_field: &Element) -> bool {
     text_field
         .clone()
@@ -30,3 +70,34 @@ pub fn text_field_is_empty(text_field: &Element) -> bool {
         .value()
         .is_empty()
 }
+
+pub fn set_info_text_ok(info_text: &Element, text: &str) {
+    info_text.class_list().remove_1("error").unwrap();
+    info_text.set_class_name("success");
+    info_text.set_inner_html(text);
+}
+
+pub fn set_info_text_error(info_text: &Element, text: &str) {
+    info_text.class_list().remove_1("success").unwrap();
+    info_text.set_class_name("error");
+    info_text.set_inner_html(text);
+}
+
+pub fn mark_textfield_validity(document: &Document, element_id: &str, is_valid: bool) {
+    let text_field = document.get_element_by_id(element_id).unwrap();
+    match is_valid {
+        true => text_field.class_list().remove_1("error").unwrap(),
+        false => text_field.set_class_name("error"),
+    };
+}
+
+pub fn download(document: &Document, url: &str, file_name: &str) {
+    let anchor = document
+        .create_element("a")
+        .unwrap()
+        .dyn_into::<HtmlAnchorElement>()
+        .unwrap();
+    anchor.set_href(url);
+    anchor.set_download(file_name);
+    anchor.click();
+}
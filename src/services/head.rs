@@ -0,0 +1,266 @@
+use crate::services::{
+    dom,
+    i18n::use_i18n,
+    routing::{self, RouterMode},
+};
+#[cfg(feature = "ssr")]
+use std::sync::{Arc, Mutex};
+use web_sys::{Document, Element};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DocumentHead {
+    pub title: String,
+    pub description: Option<String>,
+    // Defaults to the current location without query and fragment.
+    pub canonical_path: Option<String>,
+    pub image: Option<String>,
+}
+
+impl DocumentHead {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn canonical_path(mut self, path: impl Into<String>) -> Self {
+        self.canonical_path = Some(path.into());
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image = Some(url.into());
+        self
+    }
+}
+
+// A tag in the head, identified by a selector and the attribute holding its value.
+struct HeadTag {
+    element: &'static str,
+    key_attr: &'static str,
+    key: &'static str,
+    value_attr: &'static str,
+}
+
+const DESCRIPTION: HeadTag = HeadTag::meta("name", "description");
+const CANONICAL: HeadTag = HeadTag {
+    element: "link",
+    key_attr: "rel",
+    key: "canonical",
+    value_attr: "href",
+};
+const OG_TITLE: HeadTag = HeadTag::meta("property", "og:title");
+const OG_DESCRIPTION: HeadTag = HeadTag::meta("property", "og:description");
+const OG_URL: HeadTag = HeadTag::meta("property", "og:url");
+const OG_TYPE: HeadTag = HeadTag::meta("property", "og:type");
+const OG_SITE_NAME: HeadTag = HeadTag::meta("property", "og:site_name");
+const OG_LOCALE: HeadTag = HeadTag::meta("property", "og:locale");
+const OG_IMAGE: HeadTag = HeadTag::meta("property", "og:image");
+
+impl HeadTag {
+    const fn meta(key_attr: &'static str, key: &'static str) -> Self {
+        Self {
+            element: "meta",
+            key_attr,
+            key,
+            value_attr: "content",
+        }
+    }
+
+    fn find(&self, document: &Document) -> Option<Element> {
+        let selector = format!("{}[{}=\"{}\"]", self.element, self.key_attr, self.key);
+        document.query_selector(&selector).ok().flatten()
+    }
+
+    fn value(&self, document: &Document) -> Option<String> {
+        self.find(document)?.get_attribute(self.value_attr)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn markup(&self, value: &str) -> String {
+        format!(
+            "<{} {}=\"{}\" {}=\"{}\">",
+            self.element,
+            self.key_attr,
+            self.key,
+            self.value_attr,
+            escape(value)
+        )
+    }
+
+    // Tags without a value are removed, so that no stale value of another page remains.
+    fn set(&self, document: &Document, value: Option<&str>) {
+        let element = self.find(document);
+        let Some(value) = value else {
+            if let Some(element) = element {
+                element.remove();
+            }
+            return;
+        };
+        let element = element.or_else(|| {
+            let element = document.create_element(self.element).ok()?;
+            element.set_attribute(self.key_attr, self.key).ok()?;
+            document.head()?.append_child(&element).ok()?;
+            Some(element)
+        });
+        if let Some(element) = element {
+            if let Err(err) = element.set_attribute(self.value_attr, value) {
+                gloo::console::log!(err);
+            }
+        }
+    }
+}
+
+const TAGS: [&HeadTag; 9] = [
+    &DESCRIPTION,
+    &CANONICAL,
+    &OG_TITLE,
+    &OG_DESCRIPTION,
+    &OG_URL,
+    &OG_TYPE,
+    &OG_SITE_NAME,
+    &OG_LOCALE,
+    &OG_IMAGE,
+];
+
+// The document title and the values of all tags, in the order of `TAGS`.
+fn resolve(
+    head: &DocumentHead,
+    path: Option<String>,
+    origin: &str,
+    site_name: &str,
+    og_locale: &str,
+) -> (String, [Option<String>; 9]) {
+    let title = match head.title.is_empty() {
+        true => site_name.to_string(),
+        false => format!("{} | {}", head.title, site_name),
+    };
+    // Routes in the fragment are not addressable on their own, so there is no canonical URL.
+    let canonical_path = head.canonical_path.clone().or(path);
+    let url = canonical_path
+        .filter(|_| routing::router_mode() == RouterMode::Browser)
+        .map(|path| format!("{}{}", origin, routing::href(&path)));
+    let og_title = Some(head.title.clone()).filter(|title| !title.is_empty());
+    let values = [
+        head.description.clone(),
+        url.clone(),
+        og_title,
+        head.description.clone(),
+        url,
+        Some("website".to_string()),
+        Some(site_name.to_string()),
+        Some(og_locale.to_string()),
+        head.image.clone(),
+    ];
+    (title, values)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Effects do not run on the server, so the head of the rendered page is collected here
+// and inserted into the document by the server.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Debug, Default)]
+pub struct HeadCollector {
+    pub origin: String,
+    markup: Arc<Mutex<Option<String>>>,
+}
+
+#[cfg(feature = "ssr")]
+impl HeadCollector {
+    pub fn new(origin: impl Into<String>) -> Self {
+        Self {
+            origin: origin.into(),
+            ..Default::default()
+        }
+    }
+
+    fn collect(&self, title: &str, values: &[Option<String>]) {
+        let tags = TAGS
+            .iter()
+            .zip(values)
+            .filter_map(|(tag, value)| Some(tag.markup(value.as_deref()?)));
+        let markup = std::iter::once(format!("<title>{}</title>", escape(title)))
+            .chain(tags)
+            .collect::<Vec<_>>()
+            .join("");
+        *self.markup.lock().unwrap() = Some(markup);
+    }
+
+    // The head of the last screen rendered, if any.
+    pub fn markup(&self) -> Option<String> {
+        self.markup.lock().unwrap().clone()
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl PartialEq for HeadCollector {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && Arc::ptr_eq(&self.markup, &other.markup)
+    }
+}
+
+// Sets the title and meta tags of the document for as long as the calling component is
+// mounted, and restores the previous ones afterwards.
+#[hook]
+pub fn use_document_head(head: DocumentHead) {
+    let i18n = use_i18n();
+    let location = use_location();
+    let path = location.map(|location| routing::route_path(location.path()));
+    let site_name = i18n.tr("app-name");
+    let og_locale = i18n.locale.og_locale();
+
+    #[cfg(feature = "ssr")]
+    {
+        let collector = use_context::<HeadCollector>();
+        if let Some(collector) = collector {
+            let (title, values) = resolve(
+                &head,
+                path.clone(),
+                &collector.origin,
+                &site_name,
+                og_locale,
+            );
+            collector.collect(&title, &values);
+        }
+    }
+
+    use_effect_with(
+        (head, path, site_name, og_locale),
+        |(head, path, site_name, og_locale)| {
+            let window = dom::window();
+            let origin = window.location().origin().unwrap_or_default();
+            let document = dom::document(window);
+
+            let previous_title = document.title();
+            let previous_values: Vec<_> = TAGS.iter().map(|tag| tag.value(&document)).collect();
+
+            let (title, values) = resolve(head, path.clone(), &origin, site_name, og_locale);
+            document.set_title(&title);
+            for (tag, value) in TAGS.iter().zip(values) {
+                tag.set(&document, value.as_deref());
+            }
+
+            move || {
+                document.set_title(&previous_title);
+                for (tag, value) in TAGS.iter().zip(previous_values) {
+                    tag.set(&document, value.as_deref());
+                }
+            }
+        },
+    );
+}
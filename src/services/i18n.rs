@@ -0,0 +1,293 @@
+use crate::services::{
+    consent::{self, ConsentCategory},
+    dom, routing,
+};
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use gloo::storage::{LocalStorage, Storage};
+use rs_web_api_models::api_message::{
+    ApiError, ApiMessage, ApiOk, LoginError, PasswordResetError, RegistrationError,
+};
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, collections::HashMap, fmt, str::FromStr};
+use unic_langid::LanguageIdentifier;
+use yew::prelude::*;
+
+pub const LOCALE_KEY: &str = "yew-client-locale";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::German];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+        }
+    }
+
+    // Open Graph expects the language and territory, such as `en_US`.
+    pub fn og_locale(&self) -> &'static str {
+        match self {
+            Locale::English => "en_US",
+            Locale::German => "de_DE",
+        }
+    }
+
+    // Names are shown in their own language, so that they can be found in the switcher.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::German => "Deutsch",
+        }
+    }
+
+    // Accepts language tags such as `de`, `de-AT` or `de_DE`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next()?.to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    fn catalog(&self) -> &'static str {
+        match self {
+            Locale::English => include_str!("../../locales/en/main.ftl"),
+            Locale::German => include_str!("../../locales/de/main.ftl"),
+        }
+    }
+
+    fn bundle(&self) -> FluentBundle<FluentResource> {
+        let language: LanguageIdentifier = self.code().parse().unwrap();
+        let resource = FluentResource::try_new(self.catalog().to_string())
+            .expect("failed to parse translation catalog");
+        let mut bundle = FluentBundle::new(vec![language]);
+        // Unicode isolation marks would end up in input values and placeholders.
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .expect("failed to add translation catalog");
+        bundle
+    }
+}
+
+// Only exact codes are accepted, since these are used as the first segment of each route.
+impl FromStr for Locale {
+    type Err = ();
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        let locale = Self::ALL.into_iter().find(|locale| locale.code() == code);
+        locale.ok_or(())
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+thread_local! {
+    static LOCALE: Cell<Locale> = Cell::new(Locale::default());
+    static BUNDLES: HashMap<Locale, FluentBundle<FluentResource>> = Locale::ALL
+        .into_iter()
+        .map(|locale| (locale, locale.bundle()))
+        .collect();
+}
+
+pub fn current_locale() -> Locale {
+    LOCALE.with(Cell::get)
+}
+
+// The first supported one of the given language tags, in order of preference.
+pub fn negotiate<S: AsRef<str>>(languages: impl IntoIterator<Item = S>) -> Locale {
+    languages
+        .into_iter()
+        .find_map(|language| Locale::from_code(language.as_ref()))
+        .unwrap_or_default()
+}
+
+pub fn negotiate_locale() -> Locale {
+    let languages = dom::window().navigator().languages();
+    negotiate(languages.iter().filter_map(|language| language.as_string()))
+}
+
+pub fn path_locale(path: &str) -> Option<Locale> {
+    let segment = path.trim_start_matches('/').split('/').next()?;
+    segment.parse().ok()
+}
+
+// The locale in the URL wins, a stored choice is only honored as long as preferences
+// may be remembered.
+fn initial_locale() -> Locale {
+    if !dom::is_browser() {
+        return Locale::default();
+    }
+    let path = routing::current_path();
+    let stored = LocalStorage::get::<Locale>(LOCALE_KEY).ok();
+    let stored = stored.filter(|_| consent::is_granted(ConsentCategory::Preferences));
+    path_locale(&path)
+        .or(stored)
+        .unwrap_or_else(negotiate_locale)
+}
+
+// Missing keys are rendered as the key itself, so that they stand out.
+pub fn translate(locale: Locale, key: &str, args: Option<&FluentArgs>) -> String {
+    BUNDLES.with(|bundles| {
+        let bundle = &bundles[&locale];
+        let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+            gloo::console::log!(format!("missing translation for `{}`", key));
+            return key.to_string();
+        };
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        for err in errors {
+            gloo::console::log!(err.to_string());
+        }
+        text.into_owned()
+    })
+}
+
+pub fn tr(key: &str) -> String {
+    translate(current_locale(), key, None)
+}
+
+pub fn tr_args(key: &str, args: &FluentArgs) -> String {
+    translate(current_locale(), key, Some(args))
+}
+
+fn api_ok_key(ok: &ApiOk) -> &'static str {
+    match ok {
+        ApiOk::LoginSuccessful => "login-successful",
+        ApiOk::RegistrationSuccessful => "registration-successful",
+        ApiOk::PasswordResetSuccessful => "password-reset-successful",
+    }
+}
+
+fn api_error_key(err: &ApiError) -> &'static str {
+    match err {
+        ApiError::LoginError(err) => match err {
+            LoginError::InvalidLoginCredentials => "login-error-invalid-login-credentials",
+            LoginError::EmptyUserInfo => "login-error-empty-user-info",
+            LoginError::EmptyPassWord => "login-error-empty-pass-word",
+        },
+        ApiError::RegistrationError(err) => match err {
+            RegistrationError::EmptyUserName => "registration-error-empty-user-name",
+            RegistrationError::UserNameExistsAlready => {
+                "registration-error-user-name-exists-already"
+            }
+            RegistrationError::InvalidUserNameFormat => {
+                "registration-error-invalid-user-name-format"
+            }
+            RegistrationError::EmptyMailAddress => "registration-error-empty-mail-address",
+            RegistrationError::MailAddressExistsAlready => {
+                "registration-error-mail-address-exists-already"
+            }
+            RegistrationError::InvalidMailAddressFormat => {
+                "registration-error-invalid-mail-address-format"
+            }
+            RegistrationError::EmptyPassWord => "registration-error-empty-pass-word",
+            RegistrationError::InvalidPasswordFormat => {
+                "registration-error-invalid-password-format"
+            }
+            RegistrationError::EmptyPassWordConfirm => "registration-error-empty-pass-word-confirm",
+            RegistrationError::InvalidPasswordConfirmation => {
+                "registration-error-invalid-password-confirmation"
+            }
+        },
+        ApiError::PasswordResetError(PasswordResetError::EmptyUserInfo) => {
+            "password-reset-error-empty-user-info"
+        }
+        ApiError::PasswordResetError(PasswordResetError::AccountDoesNotExist) => {
+            "password-reset-error-account-does-not-exist"
+        }
+    }
+}
+
+// Every message of the models crate has a key in the catalogs, so that a new variant
+// fails to compile until it is translated.
+pub fn translate_api_message(api_msg: &ApiMessage) -> String {
+    let key = match api_msg {
+        ApiMessage::Ok(ok) => api_ok_key(ok),
+        ApiMessage::Err(err) => api_error_key(err),
+    };
+    tr(key)
+}
+
+#[derive(Clone, PartialEq)]
+pub struct I18n {
+    pub locale: Locale,
+    set_locale: Callback<Locale>,
+}
+
+impl I18n {
+    pub fn tr(&self, key: &str) -> String {
+        translate(self.locale, key, None)
+    }
+
+    pub fn tr_args(&self, key: &str, args: &FluentArgs) -> String {
+        translate(self.locale, key, Some(args))
+    }
+
+    pub fn set_locale(&self, locale: Locale) {
+        self.set_locale.emit(locale);
+    }
+}
+
+// Falls back to the current locale, so that components also render without a provider.
+#[hook]
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().unwrap_or_else(|| I18n {
+        locale: current_locale(),
+        set_locale: Callback::noop(),
+    })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct I18nProviderProps {
+    // Taken from the requested URL when rendering on the server.
+    #[prop_or_default]
+    pub locale: Option<Locale>,
+    pub children: Html,
+}
+
+#[function_component]
+pub fn I18nProvider(props: &I18nProviderProps) -> Html {
+    let locale = use_state_eq(|| props.locale.unwrap_or_else(initial_locale));
+    // Keep the global in sync before the children render, for code outside of components.
+    LOCALE.with(|current| current.set(*locale));
+
+    use_effect_with(*locale, |locale| {
+        let document = dom::document(dom::window());
+        if let Some(root) = document.document_element() {
+            if let Err(err) = root.set_attribute("lang", locale.code()) {
+                gloo::console::log!(err);
+            }
+        }
+        if consent::is_granted(ConsentCategory::Preferences) {
+            if let Err(err) = LocalStorage::set(LOCALE_KEY, locale) {
+                gloo::console::log!(err.to_string());
+            }
+        }
+    });
+
+    let set_locale = {
+        let locale = locale.clone();
+        use_callback((), move |new_locale, _| locale.set(new_locale))
+    };
+    let i18n = I18n {
+        locale: *locale,
+        set_locale,
+    };
+
+    html! {
+        <ContextProvider<I18n> context={ i18n }>
+            { props.children.clone() }
+        </ContextProvider<I18n>>
+    }
+}
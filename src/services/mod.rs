@@ -1,3 +1,9 @@
+pub mod api;
+pub mod consent;
 pub mod dom;
 pub mod hashing;
+pub mod head;
+pub mod i18n;
 pub mod routing;
+pub mod session;
+pub mod theme;
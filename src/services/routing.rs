@@ -1,34 +1,291 @@
 use crate::screens::{
-    home::HomeScreen, imprint::ImprintScreen, login::LoginScreen,
+    account::AccountScreen,
+    home::HomeScreen,
+    legal::{LegalDocument, LegalScreen},
+    login::LoginScreen,
     page_not_found::PageNotFoundScreen,
 };
+use crate::services::{
+    dom,
+    i18n::{self, use_i18n, Locale},
+};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+const ROUTER_MODE_META: &str = "meta[name=\"yew-client-router\"]";
+pub const RENDERED_PATH_META_NAME: &str = "yew-client-rendered-path";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RouterMode {
+    // Routes are regular paths, which requires the server to answer them with `index.html`.
+    #[default]
+    Browser,
+    // Routes are kept in the fragment, for hosts without rewrite rules.
+    Hash,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Placement {
+    Header,
+    Footer,
+}
+
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
     #[at("/")]
-    Home,
-    #[at("/imprint")]
-    Imprint,
-    #[at("/login")]
-    Login,
+    Root,
+    #[at("/:locale")]
+    Home { locale: Locale },
+    #[at("/:locale/imprint")]
+    Imprint { locale: Locale },
+    #[at("/:locale/privacy")]
+    Privacy { locale: Locale },
+    #[at("/:locale/terms")]
+    Terms { locale: Locale },
+    #[at("/:locale/login")]
+    Login { locale: Locale },
+    #[at("/:locale/account")]
+    Account { locale: Locale },
     #[not_found]
     #[at("/404")]
     NotFound,
 }
 
-pub fn switch(routes: Route) -> Html {
-    let screen = match routes {
-        Route::Home => html! { <HomeScreen /> },
-        Route::Imprint => html! { <ImprintScreen /> },
-        Route::Login => html! { <LoginScreen /> },
-        Route::NotFound => html! { <PageNotFoundScreen /> },
+impl Route {
+    pub fn locale(&self) -> Option<Locale> {
+        match self {
+            Route::Home { locale }
+            | Route::Imprint { locale }
+            | Route::Privacy { locale }
+            | Route::Terms { locale }
+            | Route::Login { locale }
+            | Route::Account { locale } => Some(*locale),
+            Route::Root | Route::NotFound => None,
+        }
+    }
+
+    pub fn localized(locale: Locale) -> [Route; 6] {
+        [
+            Route::Home { locale },
+            Route::Imprint { locale },
+            Route::Privacy { locale },
+            Route::Terms { locale },
+            Route::Login { locale },
+            Route::Account { locale },
+        ]
+    }
+
+    // Where the layout links the route, the login and account routes belong to the user menu.
+    pub fn placement(&self) -> Option<Placement> {
+        match self {
+            Route::Home { .. } => Some(Placement::Header),
+            Route::Imprint { .. } | Route::Privacy { .. } | Route::Terms { .. } => {
+                Some(Placement::Footer)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Route::Root | Route::Home { .. } => "nav-home",
+            Route::Imprint { .. } => "legal-imprint",
+            Route::Privacy { .. } => "legal-privacy-policy",
+            Route::Terms { .. } => "legal-terms-of-service",
+            Route::Login { .. } => "login-title",
+            Route::Account { .. } => "account-title",
+            Route::NotFound => "nav-not-found",
+        }
+    }
+
+    // The same page in another language, routes without a locale are kept as they are.
+    pub fn with_locale(&self, locale: Locale) -> Route {
+        match self {
+            Route::Home { .. } => Route::Home { locale },
+            Route::Imprint { .. } => Route::Imprint { locale },
+            Route::Privacy { .. } => Route::Privacy { locale },
+            Route::Terms { .. } => Route::Terms { locale },
+            Route::Login { .. } => Route::Login { locale },
+            Route::Account { .. } => Route::Account { locale },
+            Route::Root | Route::NotFound => self.clone(),
+        }
+    }
+}
+
+// Chosen in `index.html` with `<meta name="yew-client-router" content="hash">`, so that the
+// same build can be deployed on any host.
+pub fn router_mode() -> RouterMode {
+    if !dom::is_browser() {
+        return RouterMode::default();
+    }
+    let document = dom::document(dom::window());
+    let meta = document.query_selector(ROUTER_MODE_META).ok().flatten();
+    match meta
+        .and_then(|meta| meta.get_attribute("content"))
+        .as_deref()
+    {
+        Some("hash") => RouterMode::Hash,
+        _ => RouterMode::Browser,
+    }
+}
+
+// The subpath the app is served from, taken from `<base href="/app/">`, which trunk sets
+// with `--public-url`. Empty when served from the root.
+pub fn base_path() -> String {
+    if !dom::is_browser() {
+        return String::new();
+    }
+    let window = dom::window();
+    let origin = window.location().origin().unwrap_or_default();
+    let document = dom::document(window);
+    let base = document.query_selector("base[href]").ok().flatten();
+    let Some(href) = base.and_then(|base| base.get_attribute("href")) else {
+        return String::new();
     };
+    let path = href.strip_prefix(&origin).unwrap_or(&href);
+    path.trim_end_matches('/').to_string()
+}
+
+// The address of a route path, for links and URLs outside of the router.
+pub fn href(path: &str) -> String {
+    match router_mode() {
+        RouterMode::Browser => format!("{}{}", base_path(), path),
+        RouterMode::Hash => format!("{}/#{}", base_path(), path),
+    }
+}
+
+// The route path of a router location, which contains the base path in browser mode.
+pub fn route_path(location_path: &str) -> String {
+    let path = match router_mode() {
+        RouterMode::Browser => {
+            let base_path = base_path();
+            let path = location_path.strip_prefix(base_path.as_str());
+            let path = path.filter(|path| path.is_empty() || path.starts_with('/'));
+            path.unwrap_or(location_path).to_string()
+        }
+        RouterMode::Hash => location_path.to_string(),
+    };
+    normalize_path(&path)
+}
+
+// Static hosts redirect directories such as `/en` to `/en/`, which the router matches as
+// the same route, so paths are compared without the trailing slash.
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim_matches('/'))
+}
 
-    html!(
-        <div>
-            { screen }
-        </div>
+// The route path of an address as built by `href`, from its path and fragment.
+pub fn route_path_of(pathname: &str, hash: &str) -> String {
+    match router_mode() {
+        RouterMode::Browser => route_path(pathname),
+        RouterMode::Hash => route_path(hash.trim_start_matches('#')),
+    }
+}
+
+// The route path of the address bar, for code that runs before the router does.
+pub fn current_path() -> String {
+    let location = dom::window().location();
+    route_path_of(
+        &location.pathname().unwrap_or_default(),
+        &location.hash().unwrap_or_default(),
     )
 }
+
+// The route path a server or pre-rendered page was rendered for. Static hosts serve
+// `404.html` for any path without a file, so it can differ from the current path.
+pub fn rendered_path() -> Option<String> {
+    let document = dom::document(dom::window());
+    let selector = format!("meta[name=\"{}\"]", RENDERED_PATH_META_NAME);
+    let meta = document.query_selector(&selector).ok().flatten()?;
+    meta.get_attribute("content")
+        .map(|path| normalize_path(&path))
+}
+
+pub fn switch(routes: Route) -> Html {
+    match routes {
+        Route::Root => {
+            html! { <Redirect<Route> to={ Route::Home { locale: i18n::current_locale() } } /> }
+        }
+        Route::Home { .. } => html! { <HomeScreen /> },
+        Route::Imprint { .. } => html! { <LegalScreen document={ LegalDocument::Imprint } /> },
+        Route::Privacy { .. } => {
+            html! { <LegalScreen document={ LegalDocument::PrivacyPolicy } /> }
+        }
+        Route::Terms { .. } => html! { <LegalScreen document={ LegalDocument::TermsOfService } /> },
+        Route::Login { .. } => html! { <LoginScreen /> },
+        Route::Account { .. } => html! { <AccountScreen /> },
+        Route::NotFound => html! { <UnprefixedRedirect /> },
+    }
+}
+
+// Keeps the locale in sync with the URL and announces the translations of the current page.
+#[function_component]
+pub fn LocalizedSwitch() -> Html {
+    let i18n = use_i18n();
+    let route = use_route::<Route>();
+
+    use_effect_with(route, move |route| {
+        let locale = route.as_ref().and_then(Route::locale);
+        if let Some(locale) = locale {
+            i18n.set_locale(locale);
+        }
+        update_alternate_links(route.as_ref().filter(|_| locale.is_some()));
+    });
+
+    html! { <Switch<Route> render={ switch } /> }
+}
+
+// URLs from before the locale prefix existed are redirected to the negotiated locale.
+#[function_component]
+fn UnprefixedRedirect() -> Html {
+    let location = use_location();
+    let path = location
+        .as_ref()
+        .map(|location| route_path(location.path()));
+    let route = path
+        .filter(|path| i18n::path_locale(path).is_none())
+        .map(|path| format!("/{}{}", i18n::current_locale(), path))
+        .and_then(|path| Route::recognize(&path))
+        .filter(|route| *route != Route::NotFound);
+
+    match route {
+        Some(route) => html! { <Redirect<Route> to={ route } /> },
+        None => html! { <PageNotFoundScreen /> },
+    }
+}
+
+// The `hreflang` and path of each translation of a localized route.
+pub fn alternate_paths(route: &Route) -> Vec<(&'static str, String)> {
+    Locale::ALL
+        .into_iter()
+        .map(|locale| (locale.code(), route.with_locale(locale).to_path()))
+        .chain([("x-default", Route::Root.to_path())])
+        .collect()
+}
+
+fn update_alternate_links(route: Option<&Route>) {
+    let window = dom::window();
+    let origin = window.location().origin().unwrap_or_default();
+    let document = dom::document(window);
+    let Some(head) = document.head() else {
+        return;
+    };
+
+    while let Ok(Some(link)) = head.query_selector("link[hreflang]") {
+        link.remove();
+    }
+    let route = route.filter(|_| router_mode() == RouterMode::Browser);
+    let Some(route) = route else {
+        return;
+    };
+
+    for (hreflang, path) in alternate_paths(route) {
+        let Ok(link) = document.create_element("link") else {
+            continue;
+        };
+        let _ = link.set_attribute("rel", "alternate");
+        let _ = link.set_attribute("hreflang", hreflang);
+        let _ = link.set_attribute("href", &format!("{}{}", origin, href(&path)));
+        let _ = head.append_child(&link);
+    }
+}
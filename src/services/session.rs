@@ -0,0 +1,331 @@
+use crate::services::routing::Route;
+use crate::services::{
+    api,
+    consent::{self, ConsentCategory},
+    dom, i18n,
+};
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, SessionStorage, Storage},
+};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, rc::Rc};
+use web_sys::{wasm_bindgen::JsCast, BroadcastChannel, MessageEvent};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+const BROADCAST_CHANNEL_NAME: &str = "yew-client-session";
+pub const SESSION_MARKER_KEY: &str = "yew-client-session";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Session {
+    pub user_info: Option<String>,
+    pub access_token: Option<String>,
+    pub remember_me: bool,
+    pub deletion_scheduled_for: Option<String>,
+    pub has_outdated_terms: bool,
+    // Translation key, so that the notice follows the locale of each tab.
+    pub notice: Option<String>,
+}
+
+impl Session {
+    pub fn is_logged_in(&self) -> bool {
+        self.user_info.is_some() && self.access_token.is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SessionAction {
+    LogIn {
+        user_info: String,
+        access_token: String,
+        remember_me: bool,
+    },
+    Refresh {
+        access_token: String,
+    },
+    Rename {
+        user_info: String,
+    },
+    ScheduleDeletion {
+        scheduled_for: Option<String>,
+    },
+    ReviewTerms {
+        has_outdated_terms: bool,
+    },
+    LogOut {
+        notice: Option<String>,
+    },
+}
+
+impl Reducible for Session {
+    type Action = SessionAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let session = match action {
+            SessionAction::LogIn {
+                user_info,
+                access_token,
+                remember_me,
+            } => Session {
+                user_info: Some(user_info),
+                access_token: Some(access_token),
+                remember_me,
+                deletion_scheduled_for: None,
+                has_outdated_terms: false,
+                notice: None,
+            },
+            SessionAction::Refresh { access_token } => Session {
+                access_token: Some(access_token),
+                ..(*self).clone()
+            },
+            SessionAction::Rename { user_info } => Session {
+                user_info: Some(user_info),
+                ..(*self).clone()
+            },
+            SessionAction::ScheduleDeletion { scheduled_for } => Session {
+                deletion_scheduled_for: scheduled_for,
+                ..(*self).clone()
+            },
+            SessionAction::ReviewTerms { has_outdated_terms } => Session {
+                has_outdated_terms,
+                ..(*self).clone()
+            },
+            SessionAction::LogOut { notice } => Session {
+                notice,
+                ..Session::default()
+            },
+        };
+        session.into()
+    }
+}
+
+pub type UserContext = UseReducerHandle<Session>;
+
+// Messages exchanged between tabs: session changes are forwarded as they happen, and a
+// newly opened tab queries the others for an already established session. Access tokens
+// never leave their tab, the others obtain their own with the refresh cookie.
+#[derive(Serialize, Deserialize)]
+enum SessionMessage {
+    Action(SessionAction),
+    LoggedIn {
+        user_info: String,
+        remember_me: bool,
+    },
+    Query,
+}
+
+// The API client lives outside of the component tree, so the current session and a
+// handle to the `UserContext` reducer are mirrored here.
+thread_local! {
+    static SESSION: RefCell<Rc<Session>> = RefCell::default();
+    static DISPATCHER: RefCell<Option<Callback<SessionAction>>> = const { RefCell::new(None) };
+    static CHANNEL: Option<BroadcastChannel> = BroadcastChannel::new(BROADCAST_CHANNEL_NAME).ok();
+}
+
+pub fn access_token() -> Option<String> {
+    SESSION.with(|session| session.borrow().access_token.clone())
+}
+
+pub fn user_info() -> Option<String> {
+    SESSION.with(|session| session.borrow().user_info.clone())
+}
+
+pub fn dispatch(action: SessionAction) {
+    // Sessions are only remembered with consent, whatever was asked for at login.
+    let action = match action {
+        SessionAction::LogIn {
+            user_info,
+            access_token,
+            remember_me,
+        } => SessionAction::LogIn {
+            user_info,
+            access_token,
+            remember_me: remember_me && consent::is_granted(ConsentCategory::RememberMe),
+        },
+        action => action,
+    };
+    let message = match &action {
+        SessionAction::LogIn {
+            user_info,
+            remember_me,
+            ..
+        } => Some(SessionMessage::LoggedIn {
+            user_info: user_info.clone(),
+            remember_me: *remember_me,
+        }),
+        SessionAction::Refresh { .. } => None,
+        _ => Some(SessionMessage::Action(action.clone())),
+    };
+    if let Some(message) = message {
+        broadcast(&message);
+    }
+    apply(action);
+}
+
+fn apply(action: SessionAction) {
+    match &action {
+        SessionAction::LogIn {
+            user_info,
+            remember_me,
+            ..
+        } => store_session_marker(user_info, *remember_me),
+        SessionAction::Rename { user_info } => {
+            if let Some((_, remember_me)) = read_session_marker() {
+                store_session_marker(user_info, remember_me);
+            }
+        }
+        SessionAction::Refresh { .. }
+        | SessionAction::ScheduleDeletion { .. }
+        | SessionAction::ReviewTerms { .. } => {}
+        SessionAction::LogOut { .. } => clear_session_marker(),
+    }
+
+    SESSION.with(|session| {
+        let reduced = session.borrow().clone().reduce(action.clone());
+        *session.borrow_mut() = reduced;
+    });
+
+    let dispatcher = DISPATCHER.with(|dispatcher| dispatcher.borrow().clone());
+    if let Some(dispatcher) = dispatcher {
+        dispatcher.emit(action);
+    }
+}
+
+// Only a marker of the session is persisted, the tokens themselves are not. Remembered
+// sessions are kept in the local storage and thereby survive browser restarts, all
+// others are kept in the session storage of the tab.
+fn store_session_marker(user_info: &str, remember_me: bool) {
+    clear_session_marker();
+    let result = match remember_me {
+        true => LocalStorage::set(SESSION_MARKER_KEY, user_info),
+        false => SessionStorage::set(SESSION_MARKER_KEY, user_info),
+    };
+    if let Err(err) = result {
+        gloo::console::log!(err.to_string());
+    }
+}
+
+fn read_session_marker() -> Option<(String, bool)> {
+    if !dom::is_browser() {
+        return None;
+    }
+    if let Ok(user_info) = LocalStorage::get(SESSION_MARKER_KEY) {
+        return Some((user_info, true));
+    }
+    let user_info = SessionStorage::get(SESSION_MARKER_KEY).ok()?;
+    Some((user_info, false))
+}
+
+// A session that is not established yet might still be restored from its marker.
+pub fn is_restorable() -> bool {
+    read_session_marker().is_some()
+}
+
+fn clear_session_marker() {
+    LocalStorage::delete(SESSION_MARKER_KEY);
+    SessionStorage::delete(SESSION_MARKER_KEY);
+}
+
+fn broadcast(message: &SessionMessage) {
+    let Ok(message) = serde_json::to_string(message) else {
+        return;
+    };
+    CHANNEL.with(|channel| {
+        if let Some(channel) = channel {
+            if let Err(err) = channel.post_message(&message.into()) {
+                gloo::console::log!(err);
+            }
+        }
+    });
+}
+
+fn receive(event: &Event) {
+    let Some(message) = event
+        .dyn_ref::<MessageEvent>()
+        .and_then(|event| event.data().as_string())
+        .and_then(|message| serde_json::from_str::<SessionMessage>(&message).ok())
+    else {
+        return;
+    };
+    let session = SESSION.with(|session| session.borrow().clone());
+    match message {
+        SessionMessage::Action(action) => apply(action),
+        SessionMessage::LoggedIn {
+            user_info,
+            remember_me,
+        } => {
+            if session.is_logged_in() && session.user_info.as_ref() == Some(&user_info) {
+                return;
+            }
+            wasm_bindgen_futures::spawn_local(async move {
+                if !api::start_session(&user_info, remember_me).await {
+                    gloo::console::log!("failed to obtain an access token");
+                }
+            });
+        }
+        SessionMessage::Query => {
+            let user_info = session.user_info.clone().filter(|_| session.is_logged_in());
+            if let Some(user_info) = user_info {
+                broadcast(&SessionMessage::LoggedIn {
+                    user_info,
+                    remember_me: session.remember_me,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SessionProviderProps {
+    pub children: Html,
+}
+
+#[function_component]
+pub fn SessionProvider(props: &SessionProviderProps) -> Html {
+    let session = use_reducer(Session::default);
+    let navigator = use_navigator();
+
+    {
+        let session = session.clone();
+        use_effect_with((), move |_| {
+            let dispatcher = Callback::from(move |action| session.dispatch(action));
+            DISPATCHER.with(|d| *d.borrow_mut() = Some(dispatcher));
+
+            let listener = CHANNEL.with(|channel| {
+                let channel = channel.as_ref()?;
+                Some(EventListener::new(channel, "message", receive))
+            });
+            broadcast(&SessionMessage::Query);
+
+            if let Some((user_info, remember_me)) = read_session_marker() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    if !api::start_session(&user_info, remember_me).await {
+                        apply(SessionAction::LogOut { notice: None });
+                    }
+                });
+            }
+
+            move || {
+                drop(listener);
+                DISPATCHER.with(|d| *d.borrow_mut() = None);
+            }
+        });
+    }
+
+    // Sessions ended by the client (e.g. a failed token refresh) carry a notice,
+    // which is displayed on the login screen.
+    use_effect_with(session.notice.clone(), move |notice| {
+        if let (Some(_), Some(navigator)) = (notice, navigator) {
+            navigator.push(&Route::Login {
+                locale: i18n::current_locale(),
+            });
+        }
+    });
+
+    html! {
+        <ContextProvider<UserContext> context={ session }>
+            { props.children.clone() }
+        </ContextProvider<UserContext>>
+    }
+}
@@ -0,0 +1,297 @@
+use crate::services::{
+    consent::{self, ConsentCategory},
+    dom,
+};
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+};
+use serde::{Deserialize, Serialize};
+use stylist::StyleSource;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement, MediaQueryList};
+use yew::prelude::*;
+
+pub const THEME_KEY: &str = "yew-client-theme";
+pub const DEFAULT_FONT_FAMILY: &str = "Arial, sans-serif";
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+const HIGH_CONTRAST_QUERY: &str = "(prefers-contrast: more)";
+
+// Design tokens shared by all themes.
+const SPACING: [(&str, &str); 4] = [
+    ("--space-xs", "0.25em"),
+    ("--space-s", "0.5em"),
+    ("--space-m", "1em"),
+    ("--space-l", "2em"),
+];
+const RADII: [(&str, &str); 2] = [("--radius-s", "3px"), ("--radius-m", "5px")];
+const TYPOGRAPHY: [(&str, &str); 5] = [
+    ("--font-family", DEFAULT_FONT_FAMILY),
+    ("--font-size-base", "1em"),
+    ("--font-size-section", "1.5em"),
+    ("--font-size-title", "2.5em"),
+    ("--line-height", "1.5"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    pub fn color_scheme(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark | Theme::HighContrast => "dark",
+        }
+    }
+
+    fn colors(&self) -> [(&'static str, &'static str); 13] {
+        match self {
+            Theme::Light => [
+                ("--color-background", "#ffffff"),
+                ("--color-surface", "#ffffff"),
+                ("--color-text", "#1a1a1a"),
+                ("--color-muted", "#6b6b6b"),
+                ("--color-link", "#1a5fb4"),
+                ("--color-border", "#808080"),
+                ("--color-primary", "#808080"),
+                ("--color-on-primary", "#ffffff"),
+                ("--color-success", "#1e7b34"),
+                ("--color-error", "#d32f2f"),
+                ("--color-danger", "#d32f2f"),
+                ("--color-on-danger", "#ffffff"),
+                ("--color-overlay", "rgba(0, 0, 0, 0.5)"),
+            ],
+            Theme::Dark => [
+                ("--color-background", "#121212"),
+                ("--color-surface", "#1e1e1e"),
+                ("--color-text", "#e8e8e8"),
+                ("--color-muted", "#a0a0a0"),
+                ("--color-link", "#8ab4f8"),
+                ("--color-border", "#5c5c5c"),
+                ("--color-primary", "#5c5c5c"),
+                ("--color-on-primary", "#ffffff"),
+                ("--color-success", "#66bb6a"),
+                ("--color-error", "#ef5350"),
+                ("--color-danger", "#c62828"),
+                ("--color-on-danger", "#ffffff"),
+                ("--color-overlay", "rgba(0, 0, 0, 0.7)"),
+            ],
+            Theme::HighContrast => [
+                ("--color-background", "#000000"),
+                ("--color-surface", "#000000"),
+                ("--color-text", "#ffffff"),
+                ("--color-muted", "#ffffff"),
+                ("--color-link", "#ffff00"),
+                ("--color-border", "#ffffff"),
+                ("--color-primary", "#ffff00"),
+                ("--color-on-primary", "#000000"),
+                ("--color-success", "#00ff00"),
+                ("--color-error", "#ff8080"),
+                ("--color-danger", "#ff0000"),
+                ("--color-on-danger", "#ffffff"),
+                ("--color-overlay", "rgba(0, 0, 0, 0.85)"),
+            ],
+        }
+    }
+
+    pub fn tokens(&self) -> Vec<(&'static str, &'static str)> {
+        let mut tokens = self.colors().to_vec();
+        tokens.extend(SPACING);
+        tokens.extend(RADII);
+        tokens.extend(TYPOGRAPHY);
+        tokens
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum ThemeChoice {
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl ThemeChoice {
+    pub const ALL: [ThemeChoice; 4] = [
+        ThemeChoice::System,
+        ThemeChoice::Light,
+        ThemeChoice::Dark,
+        ThemeChoice::HighContrast,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ThemeChoice::System => "system",
+            ThemeChoice::Light => "light",
+            ThemeChoice::Dark => "dark",
+            ThemeChoice::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|choice| choice.code() == code)
+    }
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            ThemeChoice::System => "theme-system",
+            ThemeChoice::Light => "theme-light",
+            ThemeChoice::Dark => "theme-dark",
+            ThemeChoice::HighContrast => "theme-high-contrast",
+        }
+    }
+
+    pub fn resolve(&self, system_theme: Theme) -> Theme {
+        match self {
+            ThemeChoice::System => system_theme,
+            ThemeChoice::Light => Theme::Light,
+            ThemeChoice::Dark => Theme::Dark,
+            ThemeChoice::HighContrast => Theme::HighContrast,
+        }
+    }
+}
+
+fn media_query(query: &str) -> Option<MediaQueryList> {
+    if !dom::is_browser() {
+        return None;
+    }
+    dom::window().match_media(query).ok().flatten()
+}
+
+pub fn system_theme() -> Theme {
+    let matches = |query| media_query(query).is_some_and(|list| list.matches());
+    if matches(HIGH_CONTRAST_QUERY) {
+        Theme::HighContrast
+    } else if matches(DARK_QUERY) {
+        Theme::Dark
+    } else {
+        Theme::Light
+    }
+}
+
+// A stored choice is only honored as long as preferences may be remembered.
+fn stored_choice() -> ThemeChoice {
+    if !dom::is_browser() {
+        return ThemeChoice::default();
+    }
+    let stored = LocalStorage::get::<ThemeChoice>(THEME_KEY).ok();
+    stored
+        .filter(|_| consent::is_granted(ConsentCategory::Preferences))
+        .unwrap_or_default()
+}
+
+fn declarations(theme: Theme) -> String {
+    theme
+        .tokens()
+        .into_iter()
+        .chain([("color-scheme", theme.color_scheme())])
+        .map(|(name, value)| format!("{}: {};", name, value))
+        .collect()
+}
+
+// The tokens of the system theme, as a global style on the root element, so that they are
+// part of the markup rendered ahead of the client and also apply to the body.
+pub fn token_style() -> StyleSource {
+    let css = format!(
+        "{} @media {} {{ {} }} @media {} {{ {} }}",
+        declarations(Theme::Light),
+        DARK_QUERY,
+        declarations(Theme::Dark),
+        HIGH_CONTRAST_QUERY,
+        declarations(Theme::HighContrast),
+    );
+    StyleSource::try_from(css).expect("invalid token style")
+}
+
+// A chosen theme overrides the global style on the root element, until the system theme
+// is chosen again.
+fn apply_choice(choice: ThemeChoice) {
+    let document = dom::document(dom::window());
+    let Some(root) = document.document_element() else {
+        return;
+    };
+    let style = root.unchecked_into::<HtmlElement>().style();
+    let theme = choice.resolve(Theme::Light);
+    let properties = theme
+        .tokens()
+        .into_iter()
+        .chain([("color-scheme", theme.color_scheme())]);
+    for (name, value) in properties {
+        let result = match choice {
+            ThemeChoice::System => style.remove_property(name).map(drop),
+            _ => style.set_property(name, value),
+        };
+        if let Err(err) = result {
+            gloo::console::log!(err);
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct ThemeContext {
+    pub choice: ThemeChoice,
+    pub theme: Theme,
+    set_choice: Callback<ThemeChoice>,
+}
+
+impl ThemeContext {
+    pub fn set_choice(&self, choice: ThemeChoice) {
+        self.set_choice.emit(choice);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ThemeProviderProps {
+    pub children: Html,
+}
+
+#[function_component]
+pub fn ThemeProvider(props: &ThemeProviderProps) -> Html {
+    let choice = use_state_eq(stored_choice);
+    let system = use_state_eq(system_theme);
+
+    {
+        let system = system.clone();
+        use_effect_with((), move |_| {
+            let listeners: Vec<_> = [DARK_QUERY, HIGH_CONTRAST_QUERY]
+                .into_iter()
+                .filter_map(media_query)
+                .map(|list| {
+                    let system = system.clone();
+                    EventListener::new(&list, "change", move |_| system.set(system_theme()))
+                })
+                .collect();
+            move || drop(listeners)
+        });
+    }
+
+    let theme = choice.resolve(*system);
+    use_effect_with(*choice, |choice| apply_choice(*choice));
+    use_effect_with(*choice, |choice| {
+        if consent::is_granted(ConsentCategory::Preferences) {
+            if let Err(err) = LocalStorage::set(THEME_KEY, choice) {
+                gloo::console::log!(err.to_string());
+            }
+        }
+    });
+
+    let set_choice = {
+        let choice = choice.clone();
+        use_callback((), move |new_choice, _| choice.set(new_choice))
+    };
+    let context = ThemeContext {
+        choice: *choice,
+        theme,
+        set_choice,
+    };
+
+    html! {
+        <ContextProvider<ThemeContext> context={ context }>
+            { props.children.clone() }
+        </ContextProvider<ThemeContext>>
+    }
+}
@@ -0,0 +1,429 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use rs_web_api_models::{
+    api_message::{ApiError, ApiMessage, ApiOk, LoginError, PasswordResetError, RegistrationError},
+    validation,
+};
+use std::{cell::RefCell, collections::HashMap};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, HtmlInputElement, Request, Response, ResponseInit};
+use yew::AppHandle;
+use yew_client::screens::login::LoginScreen;
+use yew_client::services::{
+    api::BACKEND_URL,
+    dom,
+    i18n::{self, translate_api_message},
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const VALID_USER_NAME: &str = "test_user";
+const VALID_MAIL_ADDR: &str = "test.user@example.com";
+const VALID_PASS_WORD: &str = "Test-Pass-Word-123";
+const INVALID_USER_NAME: &str = "not a user name!";
+const INVALID_MAIL_ADDR: &str = "not-a-mail-address";
+const INVALID_PASS_WORD: &str = "short";
+
+const FIELD_IDS: [&str; 6] = [
+    "user_info",
+    "pass_word",
+    "user_name",
+    "mail_addr",
+    "pwconfirm",
+    "accept_terms",
+];
+
+const WAIT_TIMEOUT_MILLIS: f64 = 5000.;
+const POLL_INTERVAL_MILLIS: u32 = 10;
+
+type FetchStub = Closure<dyn Fn(Request) -> js_sys::Promise>;
+
+thread_local! {
+    static RESPONSES: RefCell<HashMap<String, (u16, String)>> = RefCell::new(HashMap::new());
+    static FETCH_STUB: RefCell<Option<FetchStub>> = const { RefCell::new(None) };
+}
+
+// The cases below rely on the models crate judging these values as intended.
+fn assert_fixtures() {
+    assert!(validation::is_valid_user_name(VALID_USER_NAME));
+    assert!(validation::is_valid_mail_addr(VALID_MAIL_ADDR));
+    assert!(validation::is_valid_pass_word(VALID_PASS_WORD));
+    assert!(!validation::is_valid_user_name(INVALID_USER_NAME));
+    assert!(!validation::is_valid_mail_addr(INVALID_USER_NAME));
+    assert!(!validation::is_valid_mail_addr(INVALID_MAIL_ADDR));
+    assert!(!validation::is_valid_pass_word(INVALID_PASS_WORD));
+}
+
+// Replaces `fetch`, so that requests to the backend are answered with the stubbed
+// responses of their endpoint, and with a 404 otherwise.
+fn stub_api(endpoint: &str, api_msg: &ApiMessage) {
+    let body = serde_json::to_string(api_msg).unwrap();
+    RESPONSES.with(|responses| {
+        responses
+            .borrow_mut()
+            .insert(endpoint.to_string(), (200, body))
+    });
+    FETCH_STUB.with(|stub| {
+        if stub.borrow().is_some() {
+            return;
+        }
+        let fetch = FetchStub::new(|request: Request| {
+            let url = request.url();
+            let endpoint = url.strip_prefix(BACKEND_URL).unwrap_or(&url).to_string();
+            let response = RESPONSES.with(|responses| responses.borrow().get(&endpoint).cloned());
+            let (status, body) = response.unwrap_or((404, String::new()));
+            let init = ResponseInit::new();
+            init.set_status(status);
+            let response = Response::new_with_opt_str_and_init(Some(&body), &init).unwrap();
+            js_sys::Promise::resolve(&JsValue::from(response))
+        });
+        js_sys::Reflect::set(&js_sys::global(), &"fetch".into(), fetch.as_ref()).unwrap();
+        *stub.borrow_mut() = Some(fetch);
+    });
+}
+
+struct MountedScreen {
+    handle: Option<AppHandle<LoginScreen>>,
+    root: Element,
+}
+
+impl Drop for MountedScreen {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.destroy();
+        }
+        self.root.remove();
+    }
+}
+
+async fn mount() -> MountedScreen {
+    assert_fixtures();
+    // Responses stubbed by an earlier test must not leak into this one.
+    RESPONSES.with(|responses| responses.borrow_mut().clear());
+    let document = dom::document(dom::window());
+    let root = document.create_element("div").unwrap();
+    dom::body(&document).append_child(&root).unwrap();
+    let handle = yew::Renderer::<LoginScreen>::with_root(root.clone()).render();
+    wait_for(|| find("p.title").is_some()).await;
+    MountedScreen {
+        handle: Some(handle),
+        root,
+    }
+}
+
+// Renders and stubbed requests finish at their own pace, so the screen is polled until it
+// reaches the expected state.
+async fn wait_for(condition: impl Fn() -> bool) {
+    let deadline = js_sys::Date::now() + WAIT_TIMEOUT_MILLIS;
+    while !condition() {
+        assert!(
+            js_sys::Date::now() < deadline,
+            "timed out waiting for the screen"
+        );
+        TimeoutFuture::new(POLL_INTERVAL_MILLIS).await;
+    }
+}
+
+fn find(selector: &str) -> Option<Element> {
+    let document = dom::document(dom::window());
+    document.query_selector(selector).unwrap()
+}
+
+fn input(id: &str) -> HtmlInputElement {
+    find(&format!("#{}", id))
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap()
+}
+
+fn click(selector: &str) {
+    let button = find(selector).unwrap().dyn_into::<HtmlElement>().unwrap();
+    button.click();
+}
+
+async fn click_to_title(selector: &str, key: &str) {
+    click(selector);
+    wait_for(|| title() == tr(key)).await;
+}
+
+fn title() -> String {
+    find("p.title").unwrap().text_content().unwrap_or_default()
+}
+
+fn info_text() -> Element {
+    find("#info_text").unwrap()
+}
+
+fn tr(key: &str) -> String {
+    i18n::tr(key)
+}
+
+// Fills in the fields, submits the form and returns the fields marked as invalid.
+async fn submit(fields: &[(&str, &str)], accept_terms: bool) -> Vec<&'static str> {
+    for (id, value) in fields {
+        input(id).set_value(value);
+    }
+    if find("#accept_terms").is_some() {
+        input("accept_terms").set_checked(accept_terms);
+    }
+    // The info text is filled in once the form has been checked or the response arrived.
+    let info_text = info_text();
+    info_text.set_text_content(None);
+    click("input.submit");
+    wait_for(|| !info_text.text_content().unwrap_or_default().is_empty()).await;
+    FIELD_IDS
+        .into_iter()
+        .filter(|id| find(&format!("#{}", id)).is_some())
+        .filter(|id| input(id).class_list().contains("error"))
+        .collect()
+}
+
+fn assert_info_text(class: &str, text: &str) {
+    let info_text = info_text();
+    assert!(info_text.class_list().contains(class));
+    assert_eq!(info_text.text_content().unwrap_or_default(), text);
+}
+
+fn login_error(err: LoginError) -> ApiMessage {
+    ApiMessage::Err(ApiError::LoginError(err))
+}
+
+fn registration_error(err: RegistrationError) -> ApiMessage {
+    ApiMessage::Err(ApiError::RegistrationError(err))
+}
+
+fn password_reset_error(err: PasswordResetError) -> ApiMessage {
+    ApiMessage::Err(ApiError::PasswordResetError(err))
+}
+
+async fn show_registration() {
+    click_to_title("form > input[type=button]", "registration-title").await;
+}
+
+async fn show_password_reset() {
+    click_to_title("input.left", "password-reset-title").await;
+}
+
+async fn show_login() {
+    click_to_title("input.left", "login-title").await;
+}
+
+#[wasm_bindgen_test]
+async fn switches_between_display_modes() {
+    let _screen = mount().await;
+    assert_eq!(title(), tr("login-title"));
+
+    show_password_reset().await;
+    assert!(find("#pass_word").is_none());
+    show_login().await;
+
+    show_registration().await;
+    assert!(find("#pwconfirm").is_some());
+    show_login().await;
+}
+
+#[wasm_bindgen_test]
+async fn marks_invalid_login_fields() {
+    let _screen = mount().await;
+    let cases = [
+        (
+            "",
+            VALID_PASS_WORD,
+            vec!["user_info"],
+            LoginError::EmptyUserInfo,
+        ),
+        (
+            VALID_USER_NAME,
+            "",
+            vec!["pass_word"],
+            LoginError::EmptyPassWord,
+        ),
+        (
+            INVALID_USER_NAME,
+            VALID_PASS_WORD,
+            vec!["user_info", "pass_word"],
+            LoginError::InvalidLoginCredentials,
+        ),
+        (
+            VALID_MAIL_ADDR,
+            INVALID_PASS_WORD,
+            vec!["user_info", "pass_word"],
+            LoginError::InvalidLoginCredentials,
+        ),
+    ];
+    for (user_info, pass_word, invalid, err) in cases {
+        let fields = [("user_info", user_info), ("pass_word", pass_word)];
+        assert_eq!(submit(&fields, false).await, invalid);
+        assert_info_text("error", &translate_api_message(&login_error(err)));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn marks_invalid_registration_fields() {
+    let _screen = mount().await;
+    show_registration().await;
+
+    let valid = [
+        ("user_name", VALID_USER_NAME),
+        ("mail_addr", VALID_MAIL_ADDR),
+        ("pass_word", VALID_PASS_WORD),
+        ("pwconfirm", VALID_PASS_WORD),
+    ];
+    let cases = [
+        ("user_name", "", RegistrationError::EmptyUserName),
+        (
+            "user_name",
+            INVALID_USER_NAME,
+            RegistrationError::InvalidUserNameFormat,
+        ),
+        ("mail_addr", "", RegistrationError::EmptyMailAddress),
+        (
+            "mail_addr",
+            INVALID_MAIL_ADDR,
+            RegistrationError::InvalidMailAddressFormat,
+        ),
+        ("pass_word", "", RegistrationError::EmptyPassWord),
+        (
+            "pass_word",
+            INVALID_PASS_WORD,
+            RegistrationError::InvalidPasswordFormat,
+        ),
+        ("pwconfirm", "", RegistrationError::EmptyPassWordConfirm),
+        (
+            "pwconfirm",
+            "Other-Pass-Word-123",
+            RegistrationError::InvalidPasswordConfirmation,
+        ),
+    ];
+    for (id, value, err) in cases {
+        let fields =
+            valid.map(|(field, default)| (field, if field == id { value } else { default }));
+        assert_eq!(submit(&fields, true).await, vec![id]);
+        assert_info_text("error", &translate_api_message(&registration_error(err)));
+    }
+
+    assert_eq!(submit(&valid, false).await, vec!["accept_terms"]);
+    assert_info_text("error", &tr("terms-not-accepted"));
+}
+
+#[wasm_bindgen_test]
+async fn marks_invalid_password_reset_fields() {
+    let _screen = mount().await;
+    show_password_reset().await;
+
+    let cases = [
+        ("", PasswordResetError::EmptyUserInfo),
+        (INVALID_USER_NAME, PasswordResetError::AccountDoesNotExist),
+    ];
+    for (user_info, err) in cases {
+        assert_eq!(
+            submit(&[("user_info", user_info)], false).await,
+            vec!["user_info"]
+        );
+        assert_info_text("error", &translate_api_message(&password_reset_error(err)));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn renders_login_responses() {
+    let _screen = mount().await;
+    let fields = [
+        ("user_info", VALID_USER_NAME),
+        ("pass_word", VALID_PASS_WORD),
+    ];
+
+    let cases = [
+        (
+            LoginError::InvalidLoginCredentials,
+            vec!["user_info", "pass_word"],
+        ),
+        (LoginError::EmptyUserInfo, vec!["user_info"]),
+        (LoginError::EmptyPassWord, vec!["pass_word"]),
+    ];
+    for (err, invalid) in cases {
+        let api_msg = login_error(err);
+        stub_api("/login", &api_msg);
+        assert_eq!(submit(&fields, false).await, invalid);
+        assert_info_text("error", &translate_api_message(&api_msg));
+    }
+
+    let api_msg = ApiMessage::Ok(ApiOk::LoginSuccessful);
+    stub_api("/login", &api_msg);
+    assert!(submit(&fields, false).await.is_empty());
+    assert_info_text("success", &translate_api_message(&api_msg));
+}
+
+#[wasm_bindgen_test]
+async fn renders_registration_responses() {
+    let _screen = mount().await;
+    show_registration().await;
+    let fields = [
+        ("user_name", VALID_USER_NAME),
+        ("mail_addr", VALID_MAIL_ADDR),
+        ("pass_word", VALID_PASS_WORD),
+        ("pwconfirm", VALID_PASS_WORD),
+    ];
+
+    let cases = [
+        (RegistrationError::EmptyUserName, vec!["user_name"]),
+        (RegistrationError::UserNameExistsAlready, vec!["user_name"]),
+        (RegistrationError::InvalidUserNameFormat, vec!["user_name"]),
+        (RegistrationError::EmptyMailAddress, vec!["mail_addr"]),
+        (
+            RegistrationError::MailAddressExistsAlready,
+            vec!["mail_addr"],
+        ),
+        (
+            RegistrationError::InvalidMailAddressFormat,
+            vec!["mail_addr"],
+        ),
+        (
+            RegistrationError::EmptyPassWord,
+            vec!["pass_word", "pwconfirm"],
+        ),
+        (
+            RegistrationError::InvalidPasswordFormat,
+            vec!["pass_word", "pwconfirm"],
+        ),
+        (RegistrationError::EmptyPassWordConfirm, vec!["pwconfirm"]),
+        (
+            RegistrationError::InvalidPasswordConfirmation,
+            vec!["pwconfirm"],
+        ),
+    ];
+    for (err, invalid) in cases {
+        let api_msg = registration_error(err);
+        stub_api("/register", &api_msg);
+        assert_eq!(submit(&fields, true).await, invalid);
+        assert_info_text("error", &translate_api_message(&api_msg));
+    }
+
+    let api_msg = ApiMessage::Ok(ApiOk::RegistrationSuccessful);
+    stub_api("/register", &api_msg);
+    assert!(submit(&fields, true).await.is_empty());
+    assert_info_text("success", &translate_api_message(&api_msg));
+}
+
+#[wasm_bindgen_test]
+async fn renders_password_reset_responses() {
+    let _screen = mount().await;
+    show_password_reset().await;
+    let fields = [("user_info", VALID_MAIL_ADDR)];
+
+    let api_msg = password_reset_error(PasswordResetError::AccountDoesNotExist);
+    stub_api("/reset_password", &api_msg);
+    assert_eq!(submit(&fields, false).await, vec!["user_info"]);
+    assert_info_text("error", &translate_api_message(&api_msg));
+
+    let api_msg = password_reset_error(PasswordResetError::EmptyUserInfo);
+    stub_api("/reset_password", &api_msg);
+    assert!(submit(&fields, false).await.is_empty());
+    assert_info_text("error", &translate_api_message(&api_msg));
+
+    let api_msg = ApiMessage::Ok(ApiOk::PasswordResetSuccessful);
+    stub_api("/reset_password", &api_msg);
+    assert!(submit(&fields, false).await.is_empty());
+    assert_info_text("success", &translate_api_message(&api_msg));
+}
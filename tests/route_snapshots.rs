@@ -0,0 +1,176 @@
+#![cfg(feature = "ssr")]
+
+use futures::executor::block_on;
+use stylist::{
+    manager::{render_static, StyleManager},
+    yew::ManagerProvider,
+};
+use yew::prelude::*;
+use yew::ServerRenderer;
+use yew_client::services::{
+    i18n::{I18nProvider, Locale},
+    routing::{switch, Route},
+    session::SessionProvider,
+};
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct RouteScreenProps {
+    route: Route,
+    manager: StyleManager,
+}
+
+// Renders a single route the way the app's switch does, without the layout around it.
+#[function_component]
+fn RouteScreen(props: &RouteScreenProps) -> Html {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history.push(props.route.to_path());
+
+    html! {
+        <ManagerProvider manager={ props.manager.clone() }>
+            <Router { history }>
+                <I18nProvider locale={ props.route.locale().or(Some(Locale::default())) }>
+                    <SessionProvider>
+                        { switch(props.route.clone()) }
+                    </SessionProvider>
+                </I18nProvider>
+            </Router>
+        </ManagerProvider>
+    }
+}
+
+// The markup of the route followed by the styles it registered.
+fn render(route: Route) -> String {
+    let (writer, reader) = render_static();
+    let props = move || RouteScreenProps {
+        route,
+        manager: StyleManager::builder()
+            .writer(writer)
+            .build()
+            .expect("failed to create style manager"),
+    };
+    let html = block_on(ServerRenderer::<RouteScreen>::with_props(props).render());
+    let mut styles = String::new();
+    reader
+        .read_style_data()
+        .write_static_markup(&mut styles)
+        .expect("failed to write styles");
+    let markup = without_component_state(&format!("{}\n\n{}", html, styles));
+    let markup = without_component_names(&without_style_locations(&markup));
+    stable_style_ids(&markup)
+}
+
+// Debug builds name the components in the markers around their markup.
+fn without_component_names(markup: &str) -> String {
+    const MARKER_START: &str = "<!--<";
+    const MARKER_END: &str = "]>-->";
+    let mut stripped = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find(MARKER_START) {
+        let (before, after) = rest.split_at(start + MARKER_START.len());
+        stripped.push_str(before);
+        let (closing, after) = match after.strip_prefix('/') {
+            Some(after) => ("/", after),
+            None => ("", after),
+        };
+        stripped.push_str(closing);
+        rest = match after.strip_prefix('[') {
+            Some(name) => {
+                stripped.push('[');
+                &name[name.find(MARKER_END).unwrap_or(name.len())..]
+            }
+            None => after,
+        };
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// Debug builds add the source location of each style to the classes, which would tie the
+// snapshots to line numbers and to the build profile.
+fn without_style_locations(markup: &str) -> String {
+    const CLASS_START: &str = "class=\"";
+    let mut stripped = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find(CLASS_START) {
+        let (before, after) = rest.split_at(start + CLASS_START.len());
+        stripped.push_str(before);
+        let end = after.find('"').unwrap_or(after.len());
+        let classes: Vec<&str> = after[..end]
+            .split(' ')
+            .filter(|class| !class.contains(".rs:"))
+            .collect();
+        stripped.push_str(&classes.join(" "));
+        rest = &after[end..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// The state passed on for hydration is encoded and contains the random style ids as well.
+fn without_component_state(markup: &str) -> String {
+    const STATE_START: &str = "<script type=\"application/x-yew-comp-state\">";
+    let mut stripped = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find(STATE_START) {
+        let (before, after) = rest.split_at(start + STATE_START.len());
+        stripped.push_str(before);
+        rest = &after[after.find("</script>").unwrap_or(after.len())..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// Stylist names styles with random ids, which are numbered in order of appearance instead.
+fn stable_style_ids(markup: &str) -> String {
+    let mut ids: Vec<&str> = Vec::new();
+    let mut stable = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find("stylist-") {
+        let (before, after) = rest.split_at(start + "stylist-".len());
+        stable.push_str(before);
+        let after = match after.strip_prefix("global-") {
+            Some(after) => {
+                stable.push_str("global-");
+                after
+            }
+            None => after,
+        };
+        let len = after
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .count();
+        let (id, after) = after.split_at(len);
+        let index = ids
+            .iter()
+            .position(|known| *known == id)
+            .unwrap_or_else(|| {
+                ids.push(id);
+                ids.len() - 1
+            });
+        stable.push_str(&index.to_string());
+        rest = after;
+    }
+    stable.push_str(rest);
+    stable
+}
+
+fn snapshot_name(route: &Route) -> String {
+    match route {
+        Route::Root => "root".to_string(),
+        Route::NotFound => "not_found".to_string(),
+        _ => route.to_path().trim_start_matches('/').replace('/', "_"),
+    }
+}
+
+#[test]
+fn routes_match_snapshots() {
+    let routes = [Route::Root, Route::NotFound]
+        .into_iter()
+        .chain(Locale::ALL.into_iter().flat_map(Route::localized));
+    for route in routes {
+        let name = snapshot_name(&route);
+        insta::assert_snapshot!(name.as_str(), render(route));
+    }
+}